@@ -1,8 +1,14 @@
 pub const SRC_DIR: &str = "src";
+pub const RESOURCES_DIR: &str = "resources";
+pub const OUT_DIR: &str = "out";
 pub const JAVA_FILE_EXTENSION: &str = "java";
 pub const KOTLIN_FILE_EXTENSION: &str = "kt";
+pub const JAR_FILE_EXTENSION: &str = "jar";
 pub const PROGRAM_TOML_FILE_NAME: &str = "Kojamp";
 pub const TOML_FILE_EXTENSION: &str = "toml";
 pub const README_FILE_NAME: &str = "README";
 pub const MARKDOWN_FILE_EXTENSION: &str = "md";
 pub const GIT_IGNORE_FILE_FULLNAME: &str = ".gitignore";
+pub const USER_CONFIG_FILE_NAME: &str = "config.toml";
+// dirs written at `out` by other subcommands (never packed into jars)
+pub const OUT_TOOL_DIRS: [&str; 3] = ["doc", "dist", "image"];
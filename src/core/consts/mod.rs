@@ -3,3 +3,4 @@ pub mod exit_status;
 pub mod files_and_dirs;
 pub mod program;
 pub mod report_badges;
+pub mod reserved_words;
@@ -0,0 +1,618 @@
+use super::{ProjectDependency, ProjectManifest};
+use regex::Regex;
+use roxmltree::{Document, Node};
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    path::{Path, PathBuf},
+};
+
+// parent poms (and imported boms) deeper than this are ignored
+const MAX_POM_DEPTH: usize = 8;
+const TRANSITIVE_SCOPES: [&str; 2] = ["compile", "runtime"];
+
+pub enum NodeState {
+    Resolved,
+    Missing,
+    // another version (the given one) is nearer to the project
+    Evicted(String),
+    // already resolved by a nearer (or earlier) node
+    Omitted,
+}
+
+pub struct DependencyNode {
+    name: String,
+    version: Option<String>,
+    dependency: ProjectDependency,
+    path: PathBuf,
+    state: NodeState,
+    children: Vec<usize>,
+}
+
+impl DependencyNode {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn get_dependency(&self) -> &ProjectDependency {
+        &self.dependency
+    }
+
+    pub fn get_state(&self) -> &NodeState {
+        &self.state
+    }
+
+    pub fn get_children(&self) -> &[usize] {
+        &self.children
+    }
+
+    pub fn is_project(&self) -> bool {
+        matches!(&self.dependency, ProjectDependency::Path(_))
+            && ProjectManifest::file_path(&self.path).is_file()
+    }
+}
+
+#[derive(Default)]
+struct PomEntry {
+    group: String,
+    artifact: String,
+    version: Option<String>,
+    scope: String,
+    optional: bool,
+    exclusions: Vec<String>,
+}
+
+#[derive(Default)]
+struct PomModel {
+    properties: HashMap<String, String>,
+    managed: HashMap<String, String>,
+    dependencies: Vec<PomEntry>,
+}
+
+fn child<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &str,
+) -> Option<Node<'a, 'input>> {
+    node.children().find(|c| c.has_tag_name(name))
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &str,
+) -> Vec<Node<'a, 'input>> {
+    node.children().filter(|c| c.has_tag_name(name)).collect()
+}
+
+fn text(node: Node, name: &str) -> Option<String> {
+    child(node, name)
+        .and_then(|c| c.text())
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+// `${...}` references are replaced by the pom properties (unknown ones are
+// kept as they are)
+fn resolve(value: &str, properties: &HashMap<String, String>) -> String {
+    let reference = Regex::new(r"\$\{([^}]+)\}").unwrap();
+    let mut output = value.to_string();
+
+    for _ in 0..MAX_POM_DEPTH {
+        let next = reference
+            .replace_all(&output, |c: &regex::Captures| {
+                properties.get(&c[1]).cloned().unwrap_or(c[0].to_string())
+            })
+            .to_string();
+        if next == output {
+            break;
+        }
+        output = next;
+    }
+
+    output
+}
+
+// `[1.2.3]` (a pinned version) is the only supported range
+fn plain_version(version: &str) -> String {
+    match version.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(v) if !v.contains(',') => v.trim().to_string(),
+        _ => version.to_string(),
+    }
+}
+
+fn pom_path(group: &str, artifact: &str, version: &str) -> Option<PathBuf> {
+    let mut pom = ProjectDependency::maven_repository()?;
+    pom.extend(group.split('.'));
+    pom.push(artifact);
+    pom.push(version);
+    pom.push(format!("{}-{}.pom", artifact, version));
+    Some(pom)
+}
+
+fn pom_entry(node: Node, properties: &HashMap<String, String>) -> PomEntry {
+    let field = |name: &str| text(node, name).map(|v| resolve(&v, properties));
+    let exclusions = child(node, "exclusions")
+        .map(|e| children(e, "exclusion"))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|e| {
+            format!(
+                "{}:{}",
+                text(e, "groupId").unwrap_or(String::from("*")),
+                text(e, "artifactId").unwrap_or(String::from("*"))
+            )
+        })
+        .collect();
+
+    PomEntry {
+        group: field("groupId").unwrap_or_default(),
+        artifact: field("artifactId").unwrap_or_default(),
+        version: field("version").map(|v| plain_version(&v)),
+        scope: field("scope").unwrap_or(String::from("compile")),
+        optional: field("optional").is_some_and(|o| o == "true"),
+        exclusions,
+    }
+}
+
+// the pom merged with its parents (properties, managed versions and
+// inherited dependencies)
+fn load_pom(
+    group: &str,
+    artifact: &str,
+    version: &str,
+    depth: usize,
+) -> Option<PomModel> {
+    if depth > MAX_POM_DEPTH {
+        return None;
+    }
+    let content =
+        fs::read_to_string(pom_path(group, artifact, version)?).ok()?;
+    let document = Document::parse(&content).ok()?;
+    let project = document.root_element();
+
+    let parent = child(project, "parent");
+    let mut model = parent
+        .and_then(|p| {
+            load_pom(
+                &text(p, "groupId")?,
+                &text(p, "artifactId")?,
+                &text(p, "version")?,
+                depth + 1,
+            )
+        })
+        .unwrap_or_default();
+
+    for (key, field) in [
+        ("project.groupId", "groupId"),
+        ("project.artifactId", "artifactId"),
+        ("project.version", "version"),
+    ] {
+        let value = text(project, field)
+            .or_else(|| parent.and_then(|p| text(p, field)));
+        if let Some(v) = value {
+            model.properties.insert(key.to_string(), v.clone());
+            model.properties.insert(key.replace("project.", "pom."), v);
+        }
+    }
+    if let Some(v) = parent.and_then(|p| text(p, "version")) {
+        model
+            .properties
+            .insert(String::from("project.parent.version"), v);
+    }
+    if let Some(props) = child(project, "properties") {
+        for prop in props.children().filter(|c| c.is_element()) {
+            let value = prop.text().unwrap_or_default().trim().to_string();
+            model
+                .properties
+                .insert(prop.tag_name().name().to_string(), value);
+        }
+    }
+
+    let managed = child(project, "dependencyManagement")
+        .and_then(|d| child(d, "dependencies"))
+        .map(|d| children(d, "dependency"))
+        .unwrap_or_default();
+    for entry in managed.into_iter().map(|d| pom_entry(d, &model.properties)) {
+        let version = match entry.version {
+            Some(v) => v,
+            None => continue,
+        };
+        // boms bring their own managed versions
+        if entry.scope == "import" {
+            if let Some(bom) =
+                load_pom(&entry.group, &entry.artifact, &version, depth + 1)
+            {
+                model.managed.extend(bom.managed);
+            }
+            continue;
+        }
+        model
+            .managed
+            .insert(format!("{}:{}", entry.group, entry.artifact), version);
+    }
+
+    let dependencies = child(project, "dependencies")
+        .map(|d| children(d, "dependency"))
+        .unwrap_or_default();
+    for node in dependencies {
+        let entry = pom_entry(node, &model.properties);
+        model.dependencies.push(entry);
+    }
+    for entry in model
+        .dependencies
+        .iter_mut()
+        .filter(|e| e.version.is_none())
+    {
+        let key = format!("{}:{}", entry.group, entry.artifact);
+        entry.version = model.managed.get(&key).cloned();
+    }
+
+    Some(model)
+}
+
+fn is_excluded(exclusions: &[String], group: &str, artifact: &str) -> bool {
+    exclusions.iter().any(|e| {
+        let (g, a) = e.split_once(':').unwrap_or((e, "*"));
+        (g == "*" || g == group) && (a == "*" || a == artifact)
+    })
+}
+
+// maven dependencies (from the local repository) and kojamp projects
+// resolved by the nearest wins rule: when the same artifact is reached
+// more than once, the version nearer to the project (or the one declared
+// first) is kept and the others are evicted
+pub struct DependencyTree {
+    nodes: Vec<DependencyNode>,
+    roots: Vec<usize>,
+}
+
+impl DependencyTree {
+    fn push(
+        &mut self,
+        name: String,
+        dependency: ProjectDependency,
+        base: &Path,
+    ) -> usize {
+        let path = dependency.expected_path(base).unwrap_or_default();
+        let mut version = None;
+        let state = match &dependency {
+            ProjectDependency::Maven { version: v, .. } => {
+                version = Some(v.clone());
+                match path.is_file() {
+                    true => NodeState::Resolved,
+                    false => NodeState::Missing,
+                }
+            }
+            _ if path.is_file() => NodeState::Resolved,
+            _ => match ProjectManifest::load(&path) {
+                Ok(manifest) => {
+                    version = Some(manifest.get_version().to_string());
+                    NodeState::Resolved
+                }
+                Err(_) => NodeState::Missing,
+            },
+        };
+
+        self.nodes.push(DependencyNode {
+            name,
+            version,
+            dependency,
+            path,
+            state,
+            children: Vec::new(),
+        });
+        self.nodes.len() - 1
+    }
+
+    fn manifest_children(
+        &mut self,
+        root: &Path,
+        manifest: &ProjectManifest,
+    ) -> Vec<usize> {
+        manifest
+            .get_dependencies()
+            .into_iter()
+            .filter_map(|d| d.ok())
+            .map(|(name, dependency)| self.push(name, dependency, root))
+            .collect()
+    }
+
+    pub fn resolve(root: &Path, manifest: &ProjectManifest) -> Self {
+        let mut tree = Self {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+        };
+        tree.roots = tree.manifest_children(root, manifest);
+        tree.expand(root.canonicalize().into_iter().collect());
+        tree
+    }
+
+    // a single maven artifact and its own dependencies (like tool jars)
+    pub fn resolve_artifact(
+        group: &str,
+        artifact: &str,
+        version: &str,
+    ) -> Self {
+        let mut tree = Self {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+        };
+        let dependency = ProjectDependency::Maven {
+            group: group.to_string(),
+            artifact: artifact.to_string(),
+            version: version.to_string(),
+        };
+        let name = format!("{}:{}:{}", group, artifact, version);
+        tree.roots = vec![tree.push(name, dependency, Path::new(""))];
+        tree.expand(Vec::new());
+        tree
+    }
+
+    // `projects` holds the kojamp projects already expanded
+    fn expand(&mut self, mut projects: Vec<PathBuf>) {
+        let mut winners: HashMap<String, String> = HashMap::new();
+        let mut queue: VecDeque<(usize, Vec<String>)> =
+            self.roots.iter().map(|r| (*r, Vec::new())).collect();
+
+        while let Some((index, exclusions)) = queue.pop_front() {
+            let node = &self.nodes[index];
+            let coordinate = match (&node.dependency, &node.state) {
+                (_, NodeState::Missing) => continue,
+                (
+                    ProjectDependency::Maven {
+                        group,
+                        artifact,
+                        version,
+                    },
+                    _,
+                ) => Some((group.clone(), artifact.clone(), version.clone())),
+                _ if node.is_project() => None,
+                _ => continue,
+            };
+            let mut next: Vec<(usize, Vec<String>)> = Vec::new();
+
+            match coordinate {
+                Some((group, artifact, version)) => {
+                    let key = format!("{}:{}", group, artifact);
+                    match winners.get(&key) {
+                        Some(w) if *w != version => {
+                            self.nodes[index].state =
+                                NodeState::Evicted(w.clone());
+                            continue;
+                        }
+                        Some(_) => {
+                            self.nodes[index].state = NodeState::Omitted;
+                            continue;
+                        }
+                        None => {
+                            winners.insert(key, version.clone());
+                        }
+                    }
+
+                    let pom = load_pom(&group, &artifact, &version, 0)
+                        .unwrap_or_default();
+                    for entry in pom.dependencies {
+                        let version = match entry.version {
+                            Some(v) => v,
+                            None => continue,
+                        };
+                        if !TRANSITIVE_SCOPES.contains(&entry.scope.as_str())
+                            || entry.optional
+                            || is_excluded(
+                                &exclusions,
+                                &entry.group,
+                                &entry.artifact,
+                            )
+                        {
+                            continue;
+                        }
+                        let mut child_exclusions = exclusions.clone();
+                        child_exclusions.extend(entry.exclusions);
+                        let name = format!(
+                            "{}:{}:{}",
+                            entry.group, entry.artifact, version
+                        );
+                        let dependency = ProjectDependency::Maven {
+                            group: entry.group,
+                            artifact: entry.artifact,
+                            version,
+                        };
+                        let child = self.push(name, dependency, Path::new(""));
+                        next.push((child, child_exclusions));
+                    }
+                }
+                None => {
+                    let project_root = self.nodes[index].path.clone();
+                    let canonical = project_root
+                        .canonicalize()
+                        .unwrap_or(project_root.clone());
+                    if projects.contains(&canonical) {
+                        self.nodes[index].state = NodeState::Omitted;
+                        continue;
+                    }
+                    projects.push(canonical);
+
+                    if let Ok(project) = ProjectManifest::load(&project_root) {
+                        self.nodes[index].name = project.get_name().to_string();
+                        for child in
+                            self.manifest_children(&project_root, &project)
+                        {
+                            next.push((child, exclusions.clone()));
+                        }
+                    }
+                }
+            }
+
+            self.nodes[index].children = next.iter().map(|(c, _)| *c).collect();
+            queue.extend(next);
+        }
+    }
+
+    pub fn get_node(&self, index: usize) -> &DependencyNode {
+        &self.nodes[index]
+    }
+
+    pub fn get_roots(&self) -> &[usize] {
+        &self.roots
+    }
+
+    pub fn get_nodes(&self) -> &[DependencyNode] {
+        &self.nodes
+    }
+
+    // every resolved jar file (maven and path ones)
+    pub fn jars(&self) -> Vec<PathBuf> {
+        let mut output: Vec<PathBuf> = Vec::new();
+        for node in self.nodes.iter() {
+            if matches!(node.state, NodeState::Resolved)
+                && !node.is_project()
+                && !output.contains(&node.path)
+            {
+                output.push(node.path.clone());
+            }
+        }
+        output
+    }
+
+    // names of the dependencies that couldn't be found
+    pub fn missing(&self) -> Vec<String> {
+        let mut output: Vec<String> = Vec::new();
+        for node in self.nodes.iter() {
+            if matches!(node.state, NodeState::Missing)
+                && !output.contains(&node.name)
+            {
+                output.push(node.name.clone());
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    // `(artifact, version, dependencies)`, all of them at the `com.foo` group
+    type Artifact = (
+        &'static str,
+        &'static str,
+        &'static [(&'static str, &'static str)],
+    );
+
+    // a fake local maven repository
+    const ARTIFACTS: [Artifact; 7] = [
+        ("app", "1.0", &[("lib-a", "1.0"), ("lib-b", "1.0")]),
+        (
+            "tool",
+            "1.0",
+            &[("lib-a", "1.0"), ("lib-b", "1.0"), ("common", "3.0")],
+        ),
+        ("lib-a", "1.0", &[("common", "1.0")]),
+        ("lib-b", "1.0", &[("common", "2.0")]),
+        ("common", "1.0", &[]),
+        ("common", "2.0", &[]),
+        ("common", "3.0", &[]),
+    ];
+
+    fn repository() {
+        let home = env::temp_dir().join("kojamp-dependency-tree-tests");
+        env::set_var("HOME", &home);
+
+        for (artifact, version, dependencies) in ARTIFACTS {
+            let dir = ProjectDependency::maven_repository()
+                .unwrap()
+                .join("com/foo")
+                .join(artifact)
+                .join(version);
+            fs::create_dir_all(&dir).unwrap();
+
+            let dependencies: String = dependencies
+                .iter()
+                .map(|(a, v)| {
+                    format!(
+                        "<dependency><groupId>com.foo</groupId>\
+                        <artifactId>{}</artifactId>\
+                        <version>{}</version></dependency>",
+                        a, v
+                    )
+                })
+                .collect();
+            let pom = format!(
+                "<project><groupId>com.foo</groupId>\
+                <artifactId>{}</artifactId><version>{}</version>\
+                <dependencies>{}</dependencies></project>",
+                artifact, version, dependencies
+            );
+            let file = format!("{}-{}", artifact, version);
+            fs::write(dir.join(format!("{}.pom", file)), pom).unwrap();
+            fs::write(dir.join(format!("{}.jar", file)), "").unwrap();
+        }
+    }
+
+    fn states(tree: &DependencyTree) -> Vec<String> {
+        tree.get_nodes()
+            .iter()
+            .filter(|n| n.get_name().starts_with("com.foo:common:"))
+            .map(|n| match n.get_state() {
+                NodeState::Resolved => format!("{} resolved", n.get_name()),
+                NodeState::Missing => format!("{} missing", n.get_name()),
+                NodeState::Evicted(v) => {
+                    format!("{} evicted by {}", n.get_name(), v)
+                }
+                NodeState::Omitted => format!("{} omitted", n.get_name()),
+            })
+            .collect()
+    }
+
+    fn jar_names(tree: &DependencyTree) -> Vec<String> {
+        tree.jars()
+            .iter()
+            .filter_map(|j| j.file_name())
+            .map(|j| j.to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn first_declared_wins_at_the_same_depth() {
+        repository();
+        let tree = DependencyTree::resolve_artifact("com.foo", "app", "1.0");
+
+        assert_eq!(
+            states(&tree),
+            [
+                "com.foo:common:1.0 resolved",
+                "com.foo:common:2.0 evicted by 1.0",
+            ]
+        );
+        assert_eq!(
+            jar_names(&tree),
+            [
+                "app-1.0.jar",
+                "lib-a-1.0.jar",
+                "lib-b-1.0.jar",
+                "common-1.0.jar"
+            ]
+        );
+    }
+
+    #[test]
+    fn nearest_wins_over_deeper_ones() {
+        repository();
+        let tree = DependencyTree::resolve_artifact("com.foo", "tool", "1.0");
+
+        assert_eq!(
+            states(&tree),
+            [
+                "com.foo:common:3.0 resolved",
+                "com.foo:common:1.0 evicted by 3.0",
+                "com.foo:common:2.0 evicted by 3.0",
+            ]
+        );
+        assert!(jar_names(&tree).contains(&String::from("common-3.0.jar")));
+        assert!(tree.missing().is_empty());
+    }
+}
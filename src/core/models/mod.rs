@@ -1,11 +1,28 @@
+mod dependency_tree;
 mod project_authors;
+mod project_dependency;
 mod project_fields;
+mod project_graph;
 mod project_kind;
+mod project_manifest;
 mod project_name;
 mod project_path;
+mod project_template;
+mod project_workspace;
+mod user_config;
 
-pub use project_authors::ProjectAuthors;
+pub use dependency_tree::{DependencyNode, DependencyTree, NodeState};
+pub use project_authors::{ProjectAuthor, ProjectAuthors};
+pub use project_dependency::ProjectDependency;
 pub use project_fields::ProjectFields;
+pub use project_graph::{GraphError, ProjectGraph};
 pub use project_kind::ProjectKind;
+pub use project_manifest::{
+    ManifestError, ProjectBin, ProjectManifest, DEFAULT_PROJECT_VERSION,
+    LIBRARY_PROJECT_TYPE,
+};
 pub use project_name::ProjectName;
 pub use project_path::ProjectPath;
+pub use project_template::ProjectTemplate;
+pub use project_workspace::{ProjectWorkspace, TargetError};
+pub use user_config::{UserConfig, USER_CONFIG_KEYS};
@@ -1,28 +1,89 @@
 use super::super::contracts::GetInner;
 use clap::ArgMatches;
+use regex::Regex;
+use std::fmt;
 
 #[derive(Clone)]
-pub struct ProjectAuthors(Vec<String>);
+pub struct ProjectAuthor {
+    name: String,
+    email: Option<String>,
+}
+
+impl ProjectAuthor {
+    pub fn new<T: AsRef<str>>(name: T, email: Option<T>) -> Self {
+        Self {
+            name: name.as_ref().trim().to_string(),
+            email: email
+                .map(|e| e.as_ref().trim().to_string())
+                .filter(|e| !e.is_empty()),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+}
+
+// `Name <email>` (the email part is optional)
+impl From<&str> for ProjectAuthor {
+    fn from(value: &str) -> Self {
+        let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        let re = Regex::new(r"^(.*?)\s*<([^<>]*)>$").unwrap();
+
+        match re.captures(&value) {
+            Some(c) => Self::new(&c[1], Some(&c[2])),
+            None => Self::new(value.as_str(), None),
+        }
+    }
+}
+
+impl fmt::Display for ProjectAuthor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.email {
+            Some(email) => write!(f, "{} <{}>", self.name, email),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ProjectAuthors(Vec<ProjectAuthor>);
+
+impl From<&str> for ProjectAuthors {
+    fn from(value: &str) -> Self {
+        Self(
+            value
+                .split(",")
+                .map(ProjectAuthor::from)
+                .filter(|author| !author.name.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl From<ProjectAuthor> for ProjectAuthors {
+    fn from(value: ProjectAuthor) -> Self {
+        Self(vec![value])
+    }
+}
 
 impl TryFrom<&ArgMatches> for ProjectAuthors {
     type Error = ();
 
     fn try_from(value: &ArgMatches) -> Result<Self, Self::Error> {
         let authors = value.get_one::<String>("authors").ok_or(())?;
-
-        Ok(Self(
-            authors
-                .split(",")
-                .map(|person| person.split_whitespace().collect::<Vec<_>>().join(" "))
-                .collect(),
-        ))
+        Ok(Self::from(authors.as_str()))
     }
 }
 
 impl GetInner for ProjectAuthors {
-    type Output<'a> = Vec<&'a str>;
+    type Output<'a> = &'a [ProjectAuthor];
 
     fn get_inner<'a>(&'a self) -> Self::Output<'a> {
-        self.0.iter().map(|a| a.as_ref()).collect()
+        &self.0
     }
 }
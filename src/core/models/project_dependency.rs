@@ -0,0 +1,159 @@
+use super::super::consts::files_and_dirs::{
+    JAR_FILE_EXTENSION, PROGRAM_TOML_FILE_NAME, TOML_FILE_EXTENSION,
+};
+use regex::Regex;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+pub enum ProjectDependency {
+    Maven {
+        group: String,
+        artifact: String,
+        version: String,
+    },
+    Path(PathBuf),
+}
+
+// `group:artifact:version` coordinates, anything else is taken as a path
+impl From<&str> for ProjectDependency {
+    fn from(value: &str) -> Self {
+        let coordinate =
+            Regex::new(r"^([^:/\\\s]+):([^:/\\\s]+):([^:/\\\s]+)$").unwrap();
+
+        match coordinate.captures(value.trim()) {
+            Some(c) => Self::Maven {
+                group: c[1].to_string(),
+                artifact: c[2].to_string(),
+                version: c[3].to_string(),
+            },
+            None => Self::Path(PathBuf::from(value.trim())),
+        }
+    }
+}
+
+// `name = "group:artifact:version"` or `name = { path = "..." }` entries
+impl TryFrom<&toml::Value> for ProjectDependency {
+    type Error = ();
+
+    fn try_from(value: &toml::Value) -> Result<Self, Self::Error> {
+        match value {
+            toml::Value::String(s) => match Self::from(s.as_str()) {
+                Self::Path(_) => Err(()),
+                maven => Ok(maven),
+            },
+            toml::Value::Table(t) => {
+                let path = t.get("path").and_then(|p| p.as_str()).ok_or(())?;
+                Ok(Self::Path(PathBuf::from(path)))
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+impl ProjectDependency {
+    pub fn maven_repository() -> Option<PathBuf> {
+        env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(|h| PathBuf::from(h).join(".m2").join("repository"))
+    }
+
+    // the highest version of an artifact at the local maven repository, with
+    // its dir (`Err` holds the searched location)
+    pub fn latest_installed(
+        group: &str,
+        artifact: &str,
+    ) -> Result<(PathBuf, String), PathBuf> {
+        let mut artifact_dir = Self::maven_repository().unwrap_or_default();
+        artifact_dir.extend(group.split('.'));
+        artifact_dir.push(artifact);
+
+        let version_key = |version: &str| -> Vec<u64> {
+            version
+                .split(|c: char| !c.is_ascii_digit())
+                .filter_map(|p| p.parse().ok())
+                .collect()
+        };
+        let version = artifact_dir
+            .read_dir()
+            .map_err(|_| artifact_dir.clone())?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .max_by_key(|v| version_key(v))
+            .ok_or(artifact_dir.clone())?;
+        Ok((artifact_dir.join(&version), version))
+    }
+
+    pub fn is_maven(&self) -> bool {
+        matches!(self, Self::Maven { .. })
+    }
+
+    // the key used at the `[dependencies]` table
+    pub fn default_name(&self) -> Option<String> {
+        match self {
+            Self::Maven { artifact, .. } => Some(artifact.clone()),
+            Self::Path(p)
+                if p.extension().is_some_and(|e| e == JAR_FILE_EXTENSION) =>
+            {
+                p.file_stem().map(|s| s.to_string_lossy().to_string())
+            }
+            Self::Path(p) => {
+                let absolute =
+                    env::current_dir().ok()?.join(p).canonicalize().ok()?;
+                absolute
+                    .file_name()
+                    .map(|s| s.to_string_lossy().to_string())
+            }
+        }
+    }
+
+    // where the dependency should live at this machine
+    pub fn expected_path(&self, project_root: &Path) -> Option<PathBuf> {
+        match self {
+            Self::Maven {
+                group,
+                artifact,
+                version,
+            } => {
+                let mut jar = Self::maven_repository()?;
+                jar.extend(group.split('.'));
+                jar.push(artifact);
+                jar.push(version);
+                jar.push(format!(
+                    "{}-{}.{}",
+                    artifact, version, JAR_FILE_EXTENSION
+                ));
+                Some(jar)
+            }
+            Self::Path(p) => Some(project_root.join(p)),
+        }
+    }
+
+    // a `.jar` file or a kojamp project dir (when it can be resolved)
+    pub fn local_path(&self, project_root: &Path) -> Option<PathBuf> {
+        let path = self.expected_path(project_root)?;
+        let mut toml_file = path.join(PROGRAM_TOML_FILE_NAME);
+        toml_file.set_extension(TOML_FILE_EXTENSION);
+        let is_jar = path.is_file()
+            && path.extension().is_some_and(|e| e == JAR_FILE_EXTENSION);
+        Some(path).filter(|_| is_jar || toml_file.is_file())
+    }
+
+    pub fn to_toml_value(&self) -> toml_edit::Value {
+        match self {
+            Self::Maven {
+                group,
+                artifact,
+                version,
+            } => format!("{}:{}:{}", group, artifact, version).into(),
+            Self::Path(p) => {
+                let mut table = toml_edit::InlineTable::new();
+                let path = p.to_string_lossy().replace('\\', "/");
+                table.insert("path", path.into());
+                toml_edit::Value::InlineTable(table)
+            }
+        }
+    }
+}
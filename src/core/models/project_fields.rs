@@ -1,20 +1,30 @@
 use super::{
     super::contracts::{GetInner, Unpack},
-    ProjectAuthors, ProjectKind, ProjectName,
+    ProjectAuthor, ProjectAuthors, ProjectKind, ProjectName, ProjectTemplate,
 };
 
 pub struct ProjectFields {
     name: ProjectName,
     kind: ProjectKind,
     authors: Option<ProjectAuthors>,
+    template: ProjectTemplate,
+    library: bool,
 }
 
 impl ProjectFields {
-    pub fn new(name: ProjectName, kind: ProjectKind, authors: Option<ProjectAuthors>) -> Self {
+    pub fn new(
+        name: ProjectName,
+        kind: ProjectKind,
+        authors: Option<ProjectAuthors>,
+        template: ProjectTemplate,
+        library: bool,
+    ) -> Self {
         Self {
             name,
             kind,
             authors,
+            template,
+            library,
         }
     }
 
@@ -29,10 +39,18 @@ impl ProjectFields {
     pub fn get_authors(&self) -> &Option<ProjectAuthors> {
         &self.authors
     }
+
+    pub fn get_template(&self) -> &ProjectTemplate {
+        &self.template
+    }
+
+    pub fn is_library(&self) -> bool {
+        self.library
+    }
 }
 
 impl Unpack for ProjectFields {
-    type Output<'a> = (&'a str, &'a str, Option<Vec<String>>);
+    type Output<'a> = (&'a str, &'a str, Option<&'a [ProjectAuthor]>);
 
     fn unpack<'a>(&'a self) -> Self::Output<'a> {
         (
@@ -40,7 +58,7 @@ impl Unpack for ProjectFields {
             From::from(self.get_kind()),
             self.get_authors()
                 .as_ref()
-                .map(|authors| authors.get_inner().iter().map(|a| a.to_string()).collect()),
+                .map(|authors| authors.get_inner()),
         )
     }
 }
@@ -0,0 +1,175 @@
+use super::{
+    super::consts::files_and_dirs::OUT_DIR, DependencyTree, ManifestError,
+    ProjectDependency, ProjectManifest, ProjectWorkspace,
+};
+use std::path::{Path, PathBuf};
+
+pub enum GraphError {
+    Manifest(PathBuf, ManifestError),
+    Cycle(Vec<String>),
+}
+
+pub struct ProjectNode {
+    root: PathBuf,
+    manifest: ProjectManifest,
+    dependencies: Vec<usize>,
+}
+
+impl ProjectNode {
+    pub fn get_root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn get_manifest(&self) -> &ProjectManifest {
+        &self.manifest
+    }
+}
+
+// kojamp projects linked by `{ path = "..." }` dependencies
+pub struct ProjectGraph {
+    nodes: Vec<ProjectNode>,
+}
+
+impl ProjectGraph {
+    fn node_index(&mut self, root: &Path) -> Result<usize, GraphError> {
+        let root = root.canonicalize().unwrap_or(root.to_path_buf());
+        if let Some(i) = self.nodes.iter().position(|n| n.root == root) {
+            return Ok(i);
+        }
+
+        let manifest = ProjectManifest::load(&root)
+            .map_err(|e| GraphError::Manifest(root.clone(), e))?;
+        let dependency_roots: Vec<PathBuf> = manifest
+            .get_dependencies()
+            .into_iter()
+            .filter_map(|d| match d {
+                Ok((_, ProjectDependency::Path(p))) => Some(root.join(p)),
+                _ => None,
+            })
+            .filter(|p| ProjectManifest::file_path(p).is_file())
+            .collect();
+
+        self.nodes.push(ProjectNode {
+            root,
+            manifest,
+            dependencies: Vec::new(),
+        });
+        let index = self.nodes.len() - 1;
+
+        for dep_root in dependency_roots {
+            let dep = self.node_index(&dep_root)?;
+            self.nodes[index].dependencies.push(dep);
+        }
+
+        Ok(index)
+    }
+
+    // every given project plus the ones they (transitively) depend on
+    pub fn resolve(roots: &[PathBuf]) -> Result<Self, GraphError> {
+        let mut graph = Self { nodes: Vec::new() };
+        for root in roots {
+            graph.node_index(root)?;
+        }
+        graph.build_order()?;
+        Ok(graph)
+    }
+
+    pub fn get_node(&self, index: usize) -> &ProjectNode {
+        &self.nodes[index]
+    }
+
+    pub fn find(&self, root: &Path) -> Option<usize> {
+        let root = root.canonicalize().ok()?;
+        self.nodes.iter().position(|n| n.root == root)
+    }
+
+    fn visit(
+        &self,
+        index: usize,
+        stack: &mut Vec<usize>,
+        done: &mut Vec<usize>,
+    ) -> Result<(), GraphError> {
+        if done.contains(&index) {
+            return Ok(());
+        }
+        if let Some(start) = stack.iter().position(|i| *i == index) {
+            let mut cycle: Vec<String> = stack[start..]
+                .iter()
+                .map(|i| self.nodes[*i].manifest.get_name().to_string())
+                .collect();
+            cycle.push(self.nodes[index].manifest.get_name().to_string());
+            return Err(GraphError::Cycle(cycle));
+        }
+
+        stack.push(index);
+        for dep in self.nodes[index].dependencies.iter() {
+            self.visit(*dep, stack, done)?;
+        }
+        stack.pop();
+        done.push(index);
+        Ok(())
+    }
+
+    // dependencies always come before their dependents
+    pub fn build_order(&self) -> Result<Vec<usize>, GraphError> {
+        let all: Vec<usize> = (0..self.nodes.len()).collect();
+        self.build_order_of(&all)
+    }
+
+    // the given projects along with their dependencies (transitively)
+    pub fn build_order_of(
+        &self,
+        targets: &[usize],
+    ) -> Result<Vec<usize>, GraphError> {
+        let mut done = Vec::new();
+        for index in targets {
+            self.visit(*index, &mut Vec::new(), &mut done)?;
+        }
+        Ok(done)
+    }
+
+    // dependency outputs (given by `output`) plus every needed jar (maven
+    // ones transitively, by the nearest wins rule)
+    pub fn classpath<F: Fn(usize) -> PathBuf>(
+        &self,
+        index: usize,
+        output: F,
+    ) -> (Vec<PathBuf>, Vec<String>) {
+        let node = &self.nodes[index];
+        let tree = DependencyTree::resolve(&node.root, &node.manifest);
+        let mut classpath: Vec<PathBuf> = self
+            .dependencies_of(index)
+            .into_iter()
+            .map(output)
+            .collect();
+        for jar in tree.jars() {
+            if !classpath.contains(&jar) {
+                classpath.push(jar);
+            }
+        }
+
+        (classpath, tree.missing())
+    }
+
+    // workspace members are built at the workspace `out` dir, while any
+    // other project (path dependencies included) at its own one
+    pub fn out_dir(
+        &self,
+        index: usize,
+        workspace: Option<&ProjectWorkspace>,
+    ) -> PathBuf {
+        let node = &self.nodes[index];
+        match workspace {
+            Some(ws) if ws.is_member(&node.root) => ws.out_dir(&node.manifest),
+            _ => node.root.join(OUT_DIR),
+        }
+    }
+
+    // the given project dependencies (transitively), in build order
+    pub fn dependencies_of(&self, index: usize) -> Vec<usize> {
+        let mut done = Vec::new();
+        let _ = self.visit(index, &mut Vec::new(), &mut done);
+        done.retain(|i| *i != index);
+        done
+    }
+}
@@ -9,6 +9,16 @@ pub enum ProjectKind {
     Invalid(Rc<str>),
 }
 
+impl From<&str> for ProjectKind {
+    fn from(value: &str) -> Self {
+        match value.trim().to_lowercase().as_ref() {
+            "java" => Self::Java,
+            "kotlin" => Self::Kotlin,
+            _ => Self::Invalid(Rc::from(value)),
+        }
+    }
+}
+
 impl From<&ArgMatches> for ProjectKind {
     fn from(value: &ArgMatches) -> Self {
         let value: &String = if let Some(v) = value.get_one("kind") {
@@ -17,11 +27,7 @@ impl From<&ArgMatches> for ProjectKind {
             return Self::Undefined;
         };
 
-        match value.trim().to_lowercase().as_ref() {
-            "java" => Self::Java,
-            "kotlin" => Self::Kotlin,
-            _ => Self::Invalid(Rc::from(value.as_ref())),
-        }
+        Self::from(value.as_str())
     }
 }
 
@@ -0,0 +1,251 @@
+use super::{
+    super::consts::files_and_dirs::{
+        JAR_FILE_EXTENSION, PROGRAM_TOML_FILE_NAME, RESOURCES_DIR, SRC_DIR,
+        TOML_FILE_EXTENSION,
+    },
+    ProjectAuthor, ProjectDependency,
+};
+use crate::utils::string::StringTransformation;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_PROJECT_VERSION: &str = "0.1.0";
+pub const LIBRARY_PROJECT_TYPE: &str = "lib";
+
+pub enum ManifestError {
+    Missing,
+    Unreadable,
+    Invalid,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestAuthor {
+    Text(String),
+    Entry { name: String, email: Option<String> },
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ManifestProject {
+    name: String,
+    kind: String,
+    group: Option<String>,
+    version: Option<String>,
+    main_class: Option<String>,
+    #[serde(rename = "type")]
+    project_type: Option<String>,
+    #[serde(default)]
+    authors: Vec<ManifestAuthor>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ManifestBuild {
+    jvm_target: Option<toml::Value>,
+    source_dir: Option<String>,
+    resources_dir: Option<String>,
+    #[serde(default)]
+    filter_resources: Vec<String>,
+    #[serde(default)]
+    generate_build_info: bool,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ManifestRun {
+    #[serde(default)]
+    jvm_options: Vec<String>,
+}
+
+// `[fmt]` table (the formatter jar is searched at the maven repository
+// when not given)
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ManifestFmt {
+    formatter: Option<String>,
+    jar: Option<String>,
+}
+
+// `[[bin]]` entries (extra runnable programs at the same project)
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProjectBin {
+    name: String,
+    main_class: String,
+}
+
+impl ProjectBin {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_main_class(&self) -> &str {
+        &self.main_class
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ProjectManifest {
+    project: ManifestProject,
+    #[serde(default)]
+    build: ManifestBuild,
+    #[serde(default)]
+    run: ManifestRun,
+    #[serde(default)]
+    fmt: ManifestFmt,
+    #[serde(default)]
+    bin: Vec<ProjectBin>,
+    #[serde(default)]
+    dependencies: toml::Table,
+}
+
+impl ProjectManifest {
+    pub fn file_path(project_root: &Path) -> PathBuf {
+        let mut path = project_root.join(PROGRAM_TOML_FILE_NAME);
+        path.set_extension(TOML_FILE_EXTENSION);
+        path
+    }
+
+    pub fn load(project_root: &Path) -> Result<Self, ManifestError> {
+        let path = Self::file_path(project_root);
+        if !path.is_file() {
+            return Err(ManifestError::Missing);
+        }
+        let content =
+            fs::read_to_string(path).map_err(|_| ManifestError::Unreadable)?;
+        toml::from_str(&content).map_err(|_| ManifestError::Invalid)
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.project.name
+    }
+
+    pub fn get_kind(&self) -> &str {
+        &self.project.kind
+    }
+
+    pub fn get_group(&self) -> String {
+        self.project
+            .group
+            .clone()
+            .unwrap_or(self.project.name.as_str().to_kebab_case())
+    }
+
+    pub fn get_artifact(&self) -> String {
+        self.project.name.as_str().to_kebab_case()
+    }
+
+    pub fn get_version(&self) -> &str {
+        self.project
+            .version
+            .as_deref()
+            .unwrap_or(DEFAULT_PROJECT_VERSION)
+    }
+
+    // `type = "lib"` projects have no main class
+    pub fn is_library(&self) -> bool {
+        self.project.project_type.as_deref() == Some(LIBRARY_PROJECT_TYPE)
+    }
+
+    // `<artifact>-<version>.jar`, as published to maven repositories
+    pub fn get_library_jar(&self) -> String {
+        format!(
+            "{}-{}.{}",
+            self.get_artifact(),
+            self.get_version(),
+            JAR_FILE_EXTENSION
+        )
+    }
+
+    // kotlin top-level `main` functions live at the `<File>Kt` class
+    pub fn get_main_class(&self) -> String {
+        match (&self.project.main_class, self.get_kind()) {
+            (Some(m), _) => m.clone(),
+            (None, "kotlin") => format!("{}Kt", self.project.name),
+            (None, _) => self.project.name.clone(),
+        }
+    }
+
+    // both `jvm-target = 17` and `jvm-target = "17"` are accepted
+    pub fn get_jvm_target(&self) -> Option<String> {
+        match self.build.jvm_target.as_ref()? {
+            toml::Value::Integer(i) => Some(i.to_string()),
+            other => other.as_str().map(String::from),
+        }
+    }
+
+    pub fn get_source_dir(&self) -> &str {
+        self.build.source_dir.as_deref().unwrap_or(SRC_DIR)
+    }
+
+    pub fn get_resources_dir(&self) -> &str {
+        self.build.resources_dir.as_deref().unwrap_or(RESOURCES_DIR)
+    }
+
+    // `[build] filter-resources` files (relative to the resources dir)
+    pub fn get_filtered_resources(&self) -> Vec<String> {
+        self.build
+            .filter_resources
+            .iter()
+            .map(|f| f.trim_start_matches("./").replace('\\', "/"))
+            .collect()
+    }
+
+    // `[build] generate-build-info = true`
+    pub fn generate_build_info(&self) -> bool {
+        self.build.generate_build_info
+    }
+
+    // options given to `java` by `kojamp run` (and the `dist` launchers)
+    pub fn get_jvm_options(&self) -> &[String] {
+        &self.run.jvm_options
+    }
+
+    // google-java-format for java projects, ktlint for kotlin ones
+    pub fn get_formatter(&self) -> &str {
+        match (&self.fmt.formatter, self.get_kind()) {
+            (Some(f), _) => f,
+            (None, "java") => "google-java-format",
+            (None, _) => "ktlint",
+        }
+    }
+
+    pub fn get_formatter_jar(&self) -> Option<&str> {
+        self.fmt.jar.as_deref()
+    }
+
+    pub fn get_bins(&self) -> &[ProjectBin] {
+        &self.bin
+    }
+
+    pub fn get_authors(&self) -> Vec<ProjectAuthor> {
+        self.project
+            .authors
+            .iter()
+            .map(|a| match a {
+                ManifestAuthor::Text(t) => ProjectAuthor::from(t.as_str()),
+                ManifestAuthor::Entry { name, email } => {
+                    ProjectAuthor::new(name.as_str(), email.as_deref())
+                }
+            })
+            .collect()
+    }
+
+    // entries that aren't valid dependencies are returned as `Err(name)`
+    pub fn get_dependencies(
+        &self,
+    ) -> Vec<Result<(String, ProjectDependency), String>> {
+        self.dependencies
+            .iter()
+            .map(|(name, value)| {
+                ProjectDependency::try_from(value)
+                    .map(|d| (name.clone(), d))
+                    .map_err(|_| name.clone())
+            })
+            .collect()
+    }
+}
@@ -4,6 +4,12 @@ use std::rc::Rc;
 
 pub struct ProjectName(Rc<str>);
 
+impl From<&str> for ProjectName {
+    fn from(value: &str) -> Self {
+        Self(Rc::from(value))
+    }
+}
+
 impl From<&ArgMatches> for ProjectName {
     fn from(value: &ArgMatches) -> Self {
         let value = Rc::from(value.get_one::<String>("name").unwrap().as_ref());
@@ -20,7 +26,7 @@ impl IsValid for ProjectName {
         let mut self_chars = self.0.as_ref().chars();
         let begin = self_chars.next().unwrap();
 
-        if !('A'..='Z').contains(&begin) {
+        if !begin.is_ascii_uppercase() {
             return false;
         }
 
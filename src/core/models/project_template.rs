@@ -0,0 +1,44 @@
+use super::super::contracts::IsValid;
+use clap::ArgMatches;
+use std::rc::Rc;
+
+pub enum ProjectTemplate {
+    Greeting,
+    Minimal,
+    Invalid(Rc<str>),
+}
+
+impl From<&str> for ProjectTemplate {
+    fn from(value: &str) -> Self {
+        match value.trim().to_lowercase().as_ref() {
+            "greeting" => Self::Greeting,
+            "minimal" => Self::Minimal,
+            _ => Self::Invalid(Rc::from(value)),
+        }
+    }
+}
+
+impl TryFrom<&ArgMatches> for ProjectTemplate {
+    type Error = ();
+
+    fn try_from(value: &ArgMatches) -> Result<Self, Self::Error> {
+        let template = value.get_one::<String>("template").ok_or(())?;
+        Ok(Self::from(template.as_str()))
+    }
+}
+
+impl IsValid for ProjectTemplate {
+    fn is_valid(&self) -> bool {
+        !matches!(self, Self::Invalid(_))
+    }
+}
+
+impl<'a> From<&'a ProjectTemplate> for &'a str {
+    fn from(value: &'a ProjectTemplate) -> Self {
+        match value {
+            ProjectTemplate::Greeting => "greeting",
+            ProjectTemplate::Minimal => "minimal",
+            ProjectTemplate::Invalid(x) => x,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::{
+    super::consts::files_and_dirs::OUT_DIR, GraphError, ManifestError,
+    ProjectGraph, ProjectManifest,
+};
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Deserialize)]
+struct WorkspaceTable {
+    members: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct WorkspaceFile {
+    workspace: Option<WorkspaceTable>,
+}
+
+pub enum TargetError {
+    Manifest(ManifestError),
+    NotAWorkspace,
+    Graph(GraphError),
+    UnknownMember(String, Vec<String>),
+}
+
+pub struct ProjectWorkspace {
+    root: PathBuf,
+    members: Vec<String>,
+}
+
+impl ProjectWorkspace {
+    // `None` when the `Kojamp.toml` file has no `[workspace]` table
+    pub fn load(root: &Path) -> Result<Option<Self>, ManifestError> {
+        let path = ProjectManifest::file_path(root);
+        if !path.is_file() {
+            return Err(ManifestError::Missing);
+        }
+        let content =
+            fs::read_to_string(path).map_err(|_| ManifestError::Unreadable)?;
+        let file: WorkspaceFile =
+            toml::from_str(&content).map_err(|_| ManifestError::Invalid)?;
+
+        Ok(file.workspace.map(|w| Self {
+            root: root.to_path_buf(),
+            members: w.members,
+        }))
+    }
+
+    pub fn get_members(&self) -> &[String] {
+        &self.members
+    }
+
+    pub fn member_root(&self, member: &str) -> PathBuf {
+        self.root.join(member)
+    }
+
+    // every member output lands at `<workspace>/out/<member artifact>`
+    pub fn out_dir(&self, manifest: &ProjectManifest) -> PathBuf {
+        self.root.join(OUT_DIR).join(manifest.get_artifact())
+    }
+
+    pub fn is_member(&self, project_root: &Path) -> bool {
+        self.members.iter().any(|m| {
+            self.member_root(m)
+                .canonicalize()
+                .is_ok_and(|r| r == project_root)
+        })
+    }
+
+    // members can be picked by their project name, artifact or path
+    pub fn find_member(
+        &self,
+        graph: &ProjectGraph,
+        name: &str,
+    ) -> Option<usize> {
+        self.members.iter().find_map(|m| {
+            let index = graph.find(&self.member_root(m))?;
+            let manifest = graph.get_node(index).get_manifest();
+            let matches = m.trim_end_matches('/') == name
+                || manifest.get_name() == name
+                || manifest.get_artifact() == name;
+            Some(index).filter(|_| matches)
+        })
+    }
+
+    // every workspace member at `root` (or the given one), otherwise the
+    // project there, along with the graph holding their path dependencies
+    pub fn targets(
+        root: &Path,
+        package: Option<&str>,
+    ) -> Result<(Option<Self>, ProjectGraph, Vec<usize>), TargetError> {
+        let (workspace, roots) = match (Self::load(root), package) {
+            (Ok(Some(ws)), _) => {
+                let roots: Vec<PathBuf> = ws
+                    .get_members()
+                    .iter()
+                    .map(|m| ws.member_root(m))
+                    .collect();
+                (Some(ws), roots)
+            }
+            (Err(e), _) => return Err(TargetError::Manifest(e)),
+            (_, Some(_)) => return Err(TargetError::NotAWorkspace),
+            _ => (None, vec![root.to_path_buf()]),
+        };
+
+        let graph =
+            ProjectGraph::resolve(&roots).map_err(TargetError::Graph)?;
+        let targets: Vec<usize> = match (&workspace, package) {
+            (Some(ws), Some(p)) => vec![ws.find_member(&graph, p).ok_or(
+                TargetError::UnknownMember(p.to_string(), ws.members.clone()),
+            )?],
+            _ => roots.iter().filter_map(|r| graph.find(r)).collect(),
+        };
+        Ok((workspace, graph, targets))
+    }
+}
@@ -0,0 +1,180 @@
+use super::{
+    super::{
+        consts::{
+            files_and_dirs::USER_CONFIG_FILE_NAME, program::PROGRAM_NAME,
+        },
+        contracts::IsValid,
+    },
+    ProjectAuthors, ProjectKind, ProjectTemplate,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{env, fs, path::PathBuf};
+
+pub const USER_CONFIG_KEYS: [&str; 6] = [
+    "kind",
+    "authors",
+    "git",
+    "template",
+    "package-prefix",
+    "output-format",
+];
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct UserConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authors: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    git: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_format: Option<String>,
+    // `(key, value)` pairs of the invalid `KOJAMP_*` variables
+    #[serde(skip)]
+    ignored_env: Vec<(String, String)>,
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_ref() {
+        "true" | "yes" | "1" => Some(true),
+        "false" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+impl UserConfig {
+    // `$XDG_CONFIG_HOME/kojamp/config.toml` (`~/.config` as fallback)
+    pub fn path() -> Option<PathBuf> {
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::var_os("HOME").map(|h| PathBuf::from(h).join(".config"))
+            })
+            .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+        Some(config_home.join(PROGRAM_NAME).join(USER_CONFIG_FILE_NAME))
+    }
+
+    pub fn env_var(key: &str) -> String {
+        format!("KOJAMP_{}", key.to_uppercase().replace('-', "_"))
+    }
+
+    // a missing file is the same as an empty one
+    pub fn load_file() -> Result<Self, ()> {
+        match Self::path() {
+            Some(p) if p.exists() => {
+                let content = fs::read_to_string(p).map_err(|_| ())?;
+                toml::from_str(&content).map_err(|_| ())
+            }
+            _ => Ok(Self::default()),
+        }
+    }
+
+    // file values overridden by the `KOJAMP_*` environment variables (the
+    // invalid ones are ignored, keeping the file value)
+    pub fn load() -> Result<Self, ()> {
+        let mut config = Self::load_file()?;
+        for key in USER_CONFIG_KEYS {
+            if let Ok(value) = env::var(Self::env_var(key)) {
+                if config.set(key, &value).is_err() {
+                    config.ignored_env.push((key.to_string(), value));
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn get_ignored_env(&self) -> &[(String, String)] {
+        &self.ignored_env
+    }
+
+    pub fn save(&self) -> Result<PathBuf, ()> {
+        let path = Self::path().ok_or(())?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|_| ())?;
+        }
+        let content = toml::to_string(self).map_err(|_| ())?;
+        fs::write(&path, content).map_err(|_| ())?;
+        Ok(path)
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        match key {
+            "kind" => self.kind.clone(),
+            "authors" => self.authors.clone(),
+            "git" => self.git.map(|g| g.to_string()),
+            "template" => self.template.clone(),
+            "package-prefix" => self.package_prefix.clone(),
+            "output-format" => self.output_format.clone(),
+            _ => None,
+        }
+    }
+
+    fn set_unchecked(&mut self, key: &str, value: &str) {
+        let value = value.trim().to_string();
+        match key {
+            "kind" => self.kind = Some(value),
+            "authors" => self.authors = Some(value),
+            "git" => self.git = parse_bool(&value),
+            "template" => self.template = Some(value),
+            "package-prefix" => self.package_prefix = Some(value),
+            "output-format" => self.output_format = Some(value),
+            _ => {}
+        }
+    }
+
+    pub fn value_is_valid(key: &str, value: &str) -> bool {
+        let package =
+            Regex::new(r"^[a-z_][a-z0-9_]*(\.[a-z_][a-z0-9_]*)*$").unwrap();
+        match key {
+            "kind" => ProjectKind::from(value).is_valid(),
+            "authors" => !value.trim().is_empty(),
+            "git" => parse_bool(value).is_some(),
+            "template" => ProjectTemplate::from(value).is_valid(),
+            "package-prefix" => package.is_match(value.trim()),
+            "output-format" => matches!(value.trim(), "pretty" | "plain"),
+            _ => false,
+        }
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ()> {
+        if !Self::value_is_valid(key, value) {
+            return Err(());
+        }
+        self.set_unchecked(key, value);
+        Ok(())
+    }
+
+    pub fn get_kind(&self) -> ProjectKind {
+        self.kind
+            .as_deref()
+            .map(ProjectKind::from)
+            .unwrap_or(ProjectKind::Undefined)
+    }
+
+    pub fn get_authors(&self) -> Option<ProjectAuthors> {
+        self.authors.as_deref().map(ProjectAuthors::from)
+    }
+
+    pub fn get_git(&self) -> Option<bool> {
+        self.git
+    }
+
+    pub fn get_template(&self) -> Option<ProjectTemplate> {
+        self.template.as_deref().map(ProjectTemplate::from)
+    }
+
+    pub fn get_package_prefix(&self) -> Option<&str> {
+        self.package_prefix.as_deref()
+    }
+
+    pub fn plain_output(&self) -> bool {
+        self.output_format.as_deref() == Some("plain")
+    }
+}
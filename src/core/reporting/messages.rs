@@ -1,4 +1,7 @@
-use super::super::consts::program::PROGRAM_REPO_URL;
+use super::super::{
+    consts::program::PROGRAM_REPO_URL,
+    models::{ProjectBin, UserConfig},
+};
 use super::IntoReasons;
 use crate::utils::string::StringTransformation;
 use colored::Colorize;
@@ -78,6 +81,38 @@ pub fn invalid_project_name<T: AsRef<str>>(name: T) -> String {
     )
 }
 
+pub fn invalid_project_template(template: &str) -> String {
+    format!(
+        "\
+        `{}` or `{}` template was expected\n\
+        but `{}` was found",
+        "greeting".bright_blue(),
+        "minimal".bright_blue(),
+        template.bright_red()
+    )
+}
+
+pub fn interactive_mode_without_terminal() -> String {
+    format!(
+        "\
+        The project name can only be prompted when\n\
+        stdin is a {}\n\
+        \n\
+        Consider passing it as an argument instead:\n\
+        `{}`",
+        "terminal".bright_cyan(),
+        "kojamp new <NAME> --kind <KIND>".bright_green()
+    )
+}
+
+pub fn project_creation_canceled() -> String {
+    format!(
+        "\
+        Nothing was {} to the disk",
+        "written".bright_cyan()
+    )
+}
+
 pub fn could_not_read_dir_content() -> String {
     format!(
         "\
@@ -115,6 +150,40 @@ pub fn could_not_create_dir_file(target: &str) -> String {
     )
 }
 
+// a failure while applying the whole scaffold (see `ScaffoldPlan::apply`)
+pub fn could_not_apply_scaffold(target: &str) -> String {
+    format!(
+        "\
+        {}\n\
+        \n\
+        Every file created before the failure was {}",
+        could_not_create_dir_file(target),
+        "rolled back".bright_green()
+    )
+}
+
+pub fn dir_file_already_exists(target: &str) -> String {
+    format!(
+        "\
+        The `{}` path already exists, so nothing was created\n\
+        (existing files are never overwritten)",
+        target.bright_red()
+    )
+}
+
+pub fn planned_project_tree(tree: String) -> String {
+    format!(
+        "\
+        The following files would be created:\n\
+        \n\
+        {}\n\
+        \n\
+        Nothing was {} to the disk",
+        tree,
+        "written".bright_cyan()
+    )
+}
+
 pub fn invalid_project_kind(kind_name: &str) -> String {
     let java = "Java".bright_blue();
     let kotlin = "Kotlin".bright_blue();
@@ -234,32 +303,97 @@ pub fn toml_file_could_not_be_read() -> String {
     )
 }
 
-pub fn unreadable_src_content(path: &Path) -> String {
+pub fn unreadable_src_content(source_dir: &str, path: &Path) -> String {
     format!(
         "\
         You're trying to read `{}` dir entries but get\n\
         fail at a `{}`
         ",
-        "src".bright_green(),
+        source_dir.bright_green(),
         format!("{:?}", path).bright_red()
     )
 }
 
-pub fn theres_no_files_for_the_given_project_kind(kind: &str) -> String {
+pub fn theres_no_files_for_the_given_project_kind(
+    kind: &str,
+    source_dir: &str,
+) -> String {
     format!(
         "\
         At least one {} file should be present in `{}` dir",
         kind.bright_cyan(),
-        "src".bright_green()
+        source_dir.bright_green()
     )
 }
 
-pub fn main_project_file_is_not_present(file_name: String) -> String {
+// the `main-class` line to use instead (when some main was found)
+fn main_class_suggestion(suggestion: Option<&str>) -> String {
+    match suggestion {
+        Some(s) => format!(
+            "\n\nA main entry was found at `{}`, try it at the\n\
+            `{}` file:\n\
+            \n  {}",
+            s.bright_green(),
+            "Kojamp.toml".bright_cyan(),
+            format!("main-class = \"{}\"", s).bright_green()
+        ),
+        None => format!(
+            "\n\nNo `{}` method was found at the sources",
+            "main".bright_red()
+        ),
+    }
+}
+
+pub fn main_project_file_is_not_present(
+    file_name: String,
+    suggestion: Option<&str>,
+) -> String {
     format!(
         "\
         The `{}` file was expected but\n\
-        it couldn't be found!",
-        file_name.bright_green()
+        it couldn't be found!{}",
+        file_name.bright_green(),
+        main_class_suggestion(suggestion)
+    )
+}
+
+pub fn no_mains_found(source_dir: &str) -> String {
+    format!(
+        "\
+        Neither a `{}` method nor a\n\
+        top-level `{}` was found at the `{}` dir",
+        "public static void main(String[] args)".bright_cyan(),
+        "fun main".bright_cyan(),
+        source_dir.bright_green()
+    )
+}
+
+pub fn main_class_not_listed(
+    main_class: &str,
+    suggestion: Option<&str>,
+) -> String {
+    format!(
+        "\
+        The `{}` main class isn't any of the entries above{}",
+        main_class.bright_red(),
+        main_class_suggestion(suggestion)
+    )
+}
+
+pub fn main_method_not_found(
+    main_class: &str,
+    file_name: &str,
+    suggestion: Option<&str>,
+) -> String {
+    format!(
+        "\
+        The `{}` class (`{}` file) has no\n\
+        `{}` method (or top-level `{}`){}",
+        main_class.bright_red(),
+        file_name.bright_green(),
+        "public static void main(String[] args)".bright_cyan(),
+        "fun main".bright_cyan(),
+        main_class_suggestion(suggestion)
     )
 }
 
@@ -272,6 +406,26 @@ pub fn successfully_compiled(file_count: usize) -> String {
     )
 }
 
+pub fn successfully_checked(file_count: usize) -> String {
+    format!(
+        "\
+        {} file(s) successfully compiled (nothing was written to\n\
+        the `{}` dir)",
+        file_count,
+        "out".bright_yellow()
+    )
+}
+
+pub fn library_successfully_compiled(file_count: usize, jar: &str) -> String {
+    format!(
+        "\
+        {} file(s) successfully compiled and packed into\n\
+        `{}`",
+        file_count,
+        jar.bright_green()
+    )
+}
+
 pub fn output_file_doesnt_exists(file_path: &Path) -> String {
     format!(
         "\
@@ -282,15 +436,1138 @@ pub fn output_file_doesnt_exists(file_path: &Path) -> String {
     )
 }
 
-pub fn could_not_generate_output_file_path(name: &str, kind: &str) -> String {
+pub fn user_config_could_not_be_loaded() -> String {
+    let path = UserConfig::path()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or("???".into());
+
+    format!(
+        "\
+        The `{}` file exists but it isn't a {} toml file\n\
+        \n\
+        Consider fixing it by using `{}`",
+        path.bright_red(),
+        "valid".bright_cyan(),
+        "kojamp config set <KEY> <VALUE>".bright_green()
+    )
+}
+
+pub fn could_not_save_user_config() -> String {
+    let path = UserConfig::path()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or("???".into());
+
+    format!(
+        "\
+        Error trying to write `{}`\n\
+        Probably due to {} issues",
+        path.bright_red(),
+        "permission".bright_cyan()
+    )
+}
+
+pub fn ignored_env_override(var: &str, key: &str, value: &str) -> String {
+    format!(
+        "\
+        The `{}` key doesn't accept `{}`, so the\n\
+        `{}` variable was ignored",
+        key.bright_cyan(),
+        value.bright_red(),
+        var.bright_cyan()
+    )
+}
+
+pub fn invalid_config_value(key: &str, value: &str) -> String {
+    let expected = match key {
+        "kind" => "`java` or `kotlin`",
+        "git" => "`true` or `false`",
+        "template" => "`greeting` or `minimal`",
+        "package-prefix" => "a lowercase package (`com.example`)",
+        "output-format" => "`pretty` or `plain`",
+        _ => "a non empty value",
+    };
+
+    format!(
+        "\
+        The `{}` key expects {}\n\
+        but `{}` was found",
+        key.bright_cyan(),
+        expected,
+        value.bright_red()
+    )
+}
+
+pub fn user_config_saved(path: &Path) -> String {
+    format!(
+        "\
+        Saved at `{}`\n\
+        \n\
+        {}: `KOJAMP_*` env vars and command flags still take precedence",
+        path.to_string_lossy().bright_green(),
+        "note".bright_cyan()
+    )
+}
+
+pub fn could_not_write_jar_manifest(jar: &Path) -> String {
+    format!(
+        "\
+        The project title and authors weren't added to `{}`\n\
+        \n\
+        Make sure the `{}` tool (shipped with the JDK) is available",
+        jar.to_string_lossy().bright_yellow(),
+        "jar".bright_cyan()
+    )
+}
+
+pub fn could_not_copy_resource(path: &Path) -> String {
+    format!(
+        "\
+        Error trying to copy `{}`\n\
+        Probably due to {} issue",
+        path.to_string_lossy().bright_red(),
+        "permission".bright_cyan()
+    )
+}
+
+pub fn filtered_resource_not_found(file: &str, resources_dir: &str) -> String {
+    format!(
+        "\
+        The `{}` file is listed at `{}`, but it\n\
+        isn't at the `{}` dir",
+        file.bright_red(),
+        "filter-resources".bright_cyan(),
+        resources_dir.bright_green()
+    )
+}
+
+pub fn project_with_many_modules(name: &str) -> String {
+    format!(
+        "\
+        Each module of `{}` is built at its own\n\
+        `{}` dir, so they can't be packed into a\n\
+        single jar",
+        name.bright_red(),
+        "out/<module>".bright_cyan()
+    )
+}
+
+pub fn resource_outside_modules(modules: &[String]) -> String {
+    let modules = modules
+        .iter()
+        .map(|m| format!("`{}`", m.bright_green()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "\
+        With many modules, the resources go at their module\n\
+        dir (like the sources), so it isn't visible to any of\n\
+        them\n\
+        \n\
+        Modules: {}",
+        modules
+    )
+}
+
+pub fn filtered_resource_not_text() -> String {
+    format!(
+        "\
+        Only text (utf-8) files can have their `{}`\n\
+        and `{}` placeholders replaced, so it was\n\
+        copied as is",
+        "${project.name}".bright_cyan(),
+        "${project.version}".bright_cyan()
+    )
+}
+
+pub fn build_info_with_many_modules() -> String {
+    format!(
+        "\
+        Projects with many modules can't have a\n\
+        generated `{}` class. Consider removing\n\
+        the `{}` setting",
+        "BuildInfo".bright_cyan(),
+        "generate-build-info".bright_red()
+    )
+}
+
+pub fn build_info_without_package() -> String {
+    format!(
+        "\
+        The `{}` class can't go at the default\n\
+        package (nothing could import it). Consider\n\
+        setting a packaged `{}` or a `{}`\n\
+        at the `{}` table",
+        "BuildInfo".bright_cyan(),
+        "main-class".bright_green(),
+        "group".bright_green(),
+        "[project]".bright_cyan()
+    )
+}
+
+pub fn build_info_already_defined() -> String {
+    format!(
+        "\
+        The sources already define it, so it would\n\
+        clash with the generated one. Consider\n\
+        renaming it or removing the `{}`\n\
+        setting",
+        "generate-build-info".bright_red()
+    )
+}
+
+pub fn invalid_source_name(name: &str) -> String {
+    format!(
+        "\
+        The `{}` source name isn't allowed\n\
+        \n\
+        It should follow the same rules as the project name\n\
+        ({}, starting with {})\n\
+        \n\
+        Consider using `{}` instead",
+        name.bright_red(),
+        "camel case".bright_cyan(),
+        "A-Z".bright_cyan(),
+        name.to_valid_camel_case().bright_green()
+    )
+}
+
+pub fn source_kind_mismatch(source_kind: &str, kind: &str) -> String {
+    format!(
+        "\
+        `{}` sources can't be created in a {} project",
+        source_kind.bright_red(),
+        kind.bright_cyan()
+    )
+}
+
+pub fn invalid_package(package: &str, segment: &str) -> String {
+    format!(
+        "\
+        The `{}` package isn't valid\n\
+        \n\
+        The `{}` segment isn't an identifier (or it's a reserved word)",
+        package.bright_red(),
+        segment.bright_red()
+    )
+}
+
+pub fn source_file_already_exists(path: &Path) -> String {
+    format!(
+        "\
+        The `{}` file already exists and it won't be overwritten",
+        path.to_string_lossy().bright_red()
+    )
+}
+
+pub fn source_file_created(path: &Path) -> String {
+    format!(
+        "\
+        Created at `{}`",
+        path.to_string_lossy().bright_green()
+    )
+}
+
+pub fn invalid_toml_file() -> String {
+    format!(
+        "\
+        `{}` exists but it isn't a {} toml file",
+        "Kojamp.toml".bright_cyan(),
+        "valid".bright_red()
+    )
+}
+
+pub fn could_not_name_dependency(spec: &str) -> String {
+    format!(
+        "\
+        Couldn't infer a name for `{}`\n\
+        \n\
+        Consider specifying it by using the `{}` flag",
+        spec.bright_red(),
+        "--name".bright_yellow()
+    )
+}
+
+pub fn unresolved_dependency(
+    spec: &str,
+    is_maven: bool,
+    expected: Option<&Path>,
+) -> String {
+    let expected = expected
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or("???".into());
+    let hint = if is_maven {
+        format!(
+            "The jar was expected at the local maven repository:\n`{}`",
+            expected.bright_cyan()
+        )
+    } else {
+        format!(
+            "`{}` should be a `{}` file or a directory\ncontaining a `{}` file",
+            expected.bright_cyan(),
+            ".jar".bright_green(),
+            "Kojamp.toml".bright_green()
+        )
+    };
+
+    format!(
+        "\
+        The `{}` dependency couldn't be resolved locally\n\
+        \n\
+        {}\n\
+        \n\
+        Nothing was written to `{}`",
+        spec.bright_red(),
+        hint,
+        "Kojamp.toml".bright_cyan()
+    )
+}
+
+pub fn dependency_not_found(name: &str) -> String {
+    format!(
+        "\
+        There's no `{}` entry at the `{}` table",
+        name.bright_red(),
+        "[dependencies]".bright_cyan()
+    )
+}
+
+pub fn invalid_dependency_entry(name: &str) -> String {
+    format!(
+        "\
+        The `{}` entry isn't a `{}` coordinate\n\
+        nor a `{}` table, so it was ignored",
+        name.bright_red(),
+        "\"group:artifact:version\"".bright_cyan(),
+        "{ path = \"...\" }".bright_cyan()
+    )
+}
+
+pub fn dependency_not_exported(name: &str) -> String {
+    format!(
+        "\
+        The `{}` path doesn't point to a jar file nor to\n\
+        a readable kojamp project, so it was left out",
+        name.bright_red()
+    )
+}
+
+pub fn exported_project_dependency() -> String {
+    format!(
+        "\
+        It was mapped to its maven coordinate, so it should\n\
+        be available at the {} ({})",
+        "local maven repository".bright_cyan(),
+        "~/.m2/repository".bright_green()
+    )
+}
+
+pub fn project_dependency_left_out_of_gradle(name: &str) -> String {
+    format!(
+        "\
+        The `{}` dependency is a kojamp project, which has no\n\
+        gradle build script, so it should be added by hand",
+        name.bright_red()
+    )
+}
+
+pub fn build_files_already_exist(files: &[&str]) -> String {
+    let files = files
+        .iter()
+        .map(|f| format!("`{}`", f.bright_red()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "\
+        The {} file(s) would be overwritten\n\
+        \n\
+        You can still overwrite them by using the `{}` flag",
+        files,
+        "--force".bright_yellow()
+    )
+}
+
+pub fn project_exported(
+    tool: &str,
+    files: &[&str],
+    source_dir: &str,
+) -> String {
+    let files = files
+        .iter()
+        .map(|f| format!("`{}`", f.bright_green()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let build_command = match tool {
+        "gradle" => "gradle build",
+        _ => "mvn package",
+    };
+
+    format!(
+        "\
+        {} generated. The sources stay at the `{}` dir\n\
+        \n\
+        You can now use `{}`",
+        files,
+        source_dir.bright_green(),
+        build_command.bright_green()
+    )
+}
+
+pub fn kojamp_toml_already_exists() -> String {
+    format!(
+        "\
+        This directory is already a kojamp project\n\
+        \n\
+        You can still overwrite its {} file by using the `{}` flag",
+        "Kojamp.toml".bright_cyan(),
+        "--force".bright_yellow()
+    )
+}
+
+pub fn no_build_file_found() -> String {
+    format!(
+        "\
+        A `{}`, `{}` or `{}` file\n\
+        was expected at the current directory",
+        "pom.xml".bright_cyan(),
+        "build.gradle.kts".bright_cyan(),
+        "build.gradle".bright_cyan()
+    )
+}
+
+pub fn invalid_build_file(file: &str) -> String {
+    format!(
+        "\
+        The `{}` file couldn't be parsed.\n\
+        Make sure it's a valid {} file",
+        file.bright_red(),
+        "maven project".bright_cyan()
+    )
+}
+
+pub fn invalid_imported_project_name(name: &str) -> String {
+    format!(
+        "\
+        The `{}` name (taken from the build file) isn't a valid\n\
+        kojamp project name. It should be {} (like `{}`)",
+        name.bright_red(),
+        "CamelCase".bright_cyan(),
+        "MyProject".bright_green()
+    )
+}
+
+pub fn imported_source_dir_is_missing() -> String {
+    format!(
+        "\
+        It was written to the `{}` table anyway, so you can\n\
+        move your sources there (or change the `{}` key)",
+        "[build]".bright_cyan(),
+        "source-dir".bright_cyan()
+    )
+}
+
+pub fn main_class_not_found(name: &str, kind: &str) -> String {
+    let main_file = match kind {
+        "java" => format!("{}.java", name),
+        _ => format!("{}.kt", name),
+    };
+
+    format!(
+        "\
+        The build file doesn't declare one, so `{}` will look\n\
+        for a `{}` file. You can also set the `{}` key\n\
+        at the `{}` table",
+        "kojamp build".bright_green(),
+        main_file.bright_cyan(),
+        "main-class".bright_cyan(),
+        "[project]".bright_cyan()
+    )
+}
+
+pub fn untranslated_setting(setting: &str) -> String {
+    format!(
+        "\
+        Left out: {}\n\
+        (there's no `{}` equivalent for it)",
+        setting.bright_red(),
+        "Kojamp.toml".bright_cyan()
+    )
+}
+
+pub fn project_imported(
+    build_file: &str,
+    dependency_count: usize,
+    source_dir: &str,
+) -> String {
+    format!(
+        "\
+        `{}` generated from `{}` ({} dependencies)\n\
+        The sources are read from the `{}` dir\n\
+        \n\
+        You can now use `{}`",
+        "Kojamp.toml".bright_green(),
+        build_file.bright_cyan(),
+        dependency_count,
+        source_dir.bright_green(),
+        "kojamp build".bright_green()
+    )
+}
+
+pub fn no_sources_to_adopt(kind: &str) -> String {
+    format!(
+        "\
+        No {} source file was found at the current directory\n\
+        (hidden, `{}`, `{}` and `{}` dirs aren't scanned)",
+        kind.bright_cyan(),
+        "out".bright_cyan(),
+        "build".bright_cyan(),
+        "target".bright_cyan()
+    )
+}
+
+pub fn ignored_sources(count: usize, kind: &str) -> String {
+    format!(
+        "\
+        {} source file(s) of another kind were found, but only\n\
+        the {} ones are compiled by `{}`",
+        count.to_string().bright_red(),
+        kind.bright_cyan(),
+        "kojamp build".bright_green()
+    )
+}
+
+pub fn no_main_found(name: &str, kind: &str) -> String {
+    let main_file = match kind {
+        "java" => format!("{}.java", name),
+        _ => format!("{}.kt", name),
+    };
+
+    format!(
+        "\
+        None of the sources has a main function, so `{}`\n\
+        will look for a `{}` file. You can also set the\n\
+        `{}` key at the `{}` table",
+        "kojamp build".bright_green(),
+        main_file.bright_cyan(),
+        "main-class".bright_cyan(),
+        "[project]".bright_cyan()
+    )
+}
+
+pub fn many_mains_found(mains: &[&str], chosen: &str) -> String {
+    let mains = mains
+        .iter()
+        .map(|m| format!("`{}`", m.bright_cyan()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "\
+        Found at: {}\n\
+        \n\
+        `{}` was taken as the main class (you can change\n\
+        it by editing the `{}` key)",
+        mains,
+        chosen.bright_green(),
+        "main-class".bright_cyan()
+    )
+}
+
+pub fn project_adopted(file_count: usize, source_dir: &str) -> String {
+    format!(
+        "\
+        {} source file(s) adopted from the `{}` dir\n\
+        \n\
+        You can now use `{}`",
+        file_count,
+        source_dir.bright_green(),
+        "kojamp build".bright_green()
+    )
+}
+
+pub fn already_a_kojamp_project() -> String {
+    format!(
+        "\
+        A `{}` file was found at the current directory,\n\
+        so there's nothing to adopt",
+        "Kojamp.toml".bright_cyan()
+    )
+}
+
+pub fn not_a_workspace() -> String {
+    format!(
+        "\
+        The `{}` flag can only be used at a workspace root\n\
+        (a `{}` file with a `{}` table)",
+        "--package".bright_red(),
+        "Kojamp.toml".bright_cyan(),
+        "[workspace]".bright_cyan()
+    )
+}
+
+pub fn unknown_workspace_member(name: &str, members: &[String]) -> String {
+    let members = members
+        .iter()
+        .map(|m| format!("`{}`", m.bright_green()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "\
+        There's no `{}` member at this workspace\n\
+        \n\
+        Available members: {}",
+        name.bright_red(),
+        members
+    )
+}
+
+pub fn dependency_cycle(cycle: &[String]) -> String {
+    let cycle = cycle
+        .iter()
+        .map(|c| format!("{}", c.bright_red()))
+        .collect::<Vec<_>>()
+        .join(" -> ");
+
+    format!(
+        "\
+        The projects depend on each other: {}\n\
+        Remove one of the `{}` dependencies to break it",
+        cycle,
+        "{ path = \"...\" }".bright_cyan()
+    )
+}
+
+pub fn workspace_member_required(command: &str, members: &[String]) -> String {
+    let members = members
+        .iter()
+        .map(|m| format!("`{}`", m.bright_green()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "\
+        This is a workspace root, so the member to {} should be\n\
+        specified (`{}`)\n\
+        \n\
+        Available members: {}",
+        command,
+        format!("kojamp {} -p <MEMBER>", command).bright_green(),
+        members
+    )
+}
+
+pub fn missing_dependency(name: &str) -> String {
+    format!(
+        "\
+        The `{}` dependency couldn't be found (maven ones are\n\
+        read from `{}`), so it's not at the classpath",
+        name.bright_red(),
+        "~/.m2/repository".bright_cyan()
+    )
+}
+
+pub fn maven_repository_not_found() -> String {
+    format!(
+        "\
+        The home directory couldn't be found (neither `{}` nor\n\
+        `{}` are set), so there's no `{}` to install into",
+        "HOME".bright_red(),
+        "USERPROFILE".bright_red(),
+        "~/.m2/repository".bright_cyan()
+    )
+}
+
+pub fn kotlin_application_not_installable() -> String {
+    format!(
+        "\
+        Kotlin applications are built along with the kotlin\n\
+        runtime, so only libraries can be installed\n\
+        (`{}` at the `{}` table)",
+        "type = \"lib\"".bright_green(),
+        "[project]".bright_cyan()
+    )
+}
+
+pub fn dependency_left_out_of_pom(name: &str) -> String {
+    format!(
+        "\
+        The `{}` dependency is a local jar file, which has no\n\
+        maven coordinate, so consumers should provide it themselves",
+        name.bright_red()
+    )
+}
+
+pub fn could_not_pack_jar(jar: &str) -> String {
+    format!(
+        "\
+        Error trying to create `{}`\n\
+        Make sure the `{}` tool (from the JDK) is at your {}",
+        jar.bright_red(),
+        "jar".bright_cyan(),
+        "PATH".bright_cyan()
+    )
+}
+
+pub fn could_not_write_to_repository(target: &str) -> String {
+    format!(
+        "\
+        Error trying to write `{}`\n\
+        Probably due to {} issue",
+        target.bright_red(),
+        "permission".bright_cyan()
+    )
+}
+
+pub fn project_installed(coordinate: &str, target: &str) -> String {
+    format!(
+        "\
+        The jar and pom were placed at `{}`\n\
+        \n\
+        Other projects can now depend on it with\n\
+        `{}`",
+        target.bright_green(),
+        format!("kojamp add dep {}", coordinate).bright_green()
+    )
+}
+
+pub fn project_output_not_checked() -> String {
+    format!(
+        "\
+        Only its dependencies were checked. Use `{}`\n\
+        to check the project classes too",
+        "kojamp build".bright_green()
+    )
+}
+
+pub fn unreadable_jar() -> String {
+    format!(
+        "\
+        The file isn't a valid {} archive, so its classes\n\
+        weren't checked",
+        "jar".bright_cyan()
+    )
+}
+
+pub fn duplicate_classes(paths: &[String], classes: &[String]) -> String {
+    const SHOWN_CLASSES: usize = 5;
+
+    let paths = paths
+        .iter()
+        .map(|p| format!("`{}`", p.bright_red()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut shown = classes
+        .iter()
+        .take(SHOWN_CLASSES)
+        .map(|c| format!("  * {}", c.bright_cyan()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if classes.len() > SHOWN_CLASSES {
+        shown.push_str(&format!(
+            "\n  ... and {} more",
+            classes.len() - SHOWN_CLASSES
+        ));
+    }
+
+    format!(
+        "\
+        {} class(es) are defined by {}\n\
+        (only the first one at the classpath is loaded):\n\
+        \n\
+        {}",
+        classes.len(),
+        paths,
+        shown
+    )
+}
+
+pub fn classpath_entries_checked(entries: usize) -> String {
+    format!(
+        "\
+        {} classpath entries checked",
+        entries.to_string().bright_green()
+    )
+}
+
+pub fn library_cant_be_run(name: &str) -> String {
+    format!(
+        "\
+        `{}` is a library ({}), so it has no main class.\n\
+        Use `{}` to pack its jar or depend on it from\n\
+        another project",
+        name.bright_red(),
+        "type = \"lib\"".bright_cyan(),
+        "kojamp build".bright_green()
+    )
+}
+
+pub fn bin_class_not_found(main_class: &str) -> String {
+    format!(
+        "\
+        The `{}` class isn't at the build output. Make sure\n\
+        the `{}` value matches the class package and name\n\
+        (kotlin top-level mains live at `{}` classes)",
+        main_class.bright_red(),
+        "main-class".bright_cyan(),
+        "<File>Kt".bright_cyan()
+    )
+}
+
+fn bin_choices(bins: &[ProjectBin]) -> String {
+    bins.iter()
+        .map(|bin| {
+            format!(
+                "  * {} ({})",
+                bin.get_name().bright_green(),
+                bin.get_main_class().bright_cyan()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn no_bin_entries(name: &str) -> String {
+    format!(
+        "\
+        Can't run `{}` since the `{}` file has no\n\
+        `{}` entries (only the main class can be run)",
+        name.bright_red(),
+        "Kojamp.toml".bright_cyan(),
+        "[[bin]]".bright_cyan()
+    )
+}
+
+pub fn unknown_bin(name: &str, bins: &[ProjectBin]) -> String {
+    format!(
+        "\
+        There's no `{}` entry. Available ones:\n\
+        \n\
+        {}",
+        name.bright_red(),
+        bin_choices(bins)
+    )
+}
+
+pub fn bin_required(bins: &[ProjectBin]) -> String {
+    format!(
+        "\
+        This project has many runnable entries, so the one to run\n\
+        should be specified (`{}`):\n\
+        \n\
+        {}",
+        "kojamp run --bin <NAME>".bright_green(),
+        bin_choices(bins)
+    )
+}
+
+fn package_or_default(package: &str) -> String {
+    match package {
+        "" => String::from("(default)"),
+        p => p.to_string(),
+    }
+}
+
+pub fn package_doesnt_match_dir(declared: &str, expected: &str) -> String {
+    format!(
+        "\
+        The `{}` package is declared, but the file dir\n\
+        stands for the `{}` package",
+        package_or_default(declared).bright_red(),
+        package_or_default(expected).bright_green()
+    )
+}
+
+pub fn public_class_doesnt_match_file(class: &str) -> String {
+    format!(
+        "\
+        The `{}` public class should be declared at a\n\
+        `{}` file (the compiler will refuse it)",
+        class.bright_red(),
+        format!("{}.java", class).bright_green()
+    )
+}
+
+pub fn naming_convention(item: &str, name: &str, expected: &str) -> String {
+    format!(
+        "\
+        The `{}` {} name doesn't follow the conventions\n\
+        (expected something like `{}`)",
+        name.bright_red(),
+        item,
+        expected.bright_green()
+    )
+}
+
+pub fn source_outside_source_dir(file: &str) -> String {
+    format!(
+        "\
+        The `{}` file is never compiled since it isn't\n\
+        under the source dir",
+        file.bright_red()
+    )
+}
+
+pub fn duplicate_class(locations: &[String]) -> String {
+    let locations = locations
+        .iter()
+        .map(|l| format!("  * {}", l.bright_cyan()))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "\
+        The class is declared more than once:\n\
+        \n\
+        {}",
+        locations
+    )
+}
+
+pub fn module_not_found(module: &str, optional: bool) -> String {
+    let note = match optional {
+        true => format!(
+            "\n\
+            \n\
+            It's a `{}` one, so only the compiler needs it",
+            "requires static".bright_cyan()
+        ),
+        false => String::new(),
+    };
+
+    format!(
+        "\
+        The `{}` module isn't at the module path (nor at the\n\
+        JDK). Add the dependency holding it (`{}`)\n\
+        or remove the `{}` line{}",
+        module.bright_red(),
+        "kojamp add dep".bright_green(),
+        "requires".bright_cyan(),
+        note
+    )
+}
+
+pub fn module_dir_doesnt_match(module: &str, dir: &str) -> String {
+    format!(
+        "\
+        The `{}` module is declared at the `{}` dir, but\n\
+        module dirs should be named after their modules (the\n\
+        compiler looks for `{}`)",
+        module.bright_green(),
+        dir.bright_red(),
+        format!("<source dir>/{}", module).bright_green()
+    )
+}
+
+pub fn lint_findings(errors: usize, warnings: usize) -> String {
+    format!(
+        "\
+        {} error(s) and {} warning(s) were found",
+        errors.to_string().bright_red(),
+        warnings.to_string().bright_yellow()
+    )
+}
+
+pub fn lint_passed(files: usize, warnings: usize) -> String {
+    format!(
+        "\
+        {} file(s) checked, {} warning(s) found (use `{}`\n\
+        to fail on them)",
+        files,
+        warnings.to_string().bright_yellow(),
+        "--deny warnings".bright_cyan()
+    )
+}
+
+pub fn unknown_formatter(name: &str, kind: &str) -> String {
+    format!(
+        "\
+        The `{}` formatter isn't available for {} projects\n\
+        (use `{}` for java and `{}`/`{}` for kotlin)",
+        name.bright_red(),
+        kind.bright_cyan(),
+        "google-java-format".bright_green(),
+        "ktlint".bright_green(),
+        "ktfmt".bright_green()
+    )
+}
+
+pub fn formatter_not_found(searched: &Path) -> String {
+    format!(
+        "\
+        Nothing was found at\n\
+        `{}`\n\
+        \n\
+        Install the formatter at the local maven repository or set\n\
+        its jar at the `{}` file:\n\
+        \n  {}\n  {}",
+        searched.to_string_lossy().bright_red(),
+        "Kojamp.toml".bright_cyan(),
+        "[fmt]".bright_green(),
+        "jar = \"tools/formatter-all-deps.jar\"".bright_green()
+    )
+}
+
+pub fn unformatted_file(formatter: &str) -> String {
+    format!(
+        "\
+        The file doesn't follow the `{}` style (use\n\
+        `{}` to format it)",
+        formatter.bright_cyan(),
+        "kojamp fmt".bright_green()
+    )
+}
+
+pub fn unformatted_sources(count: usize) -> String {
+    format!(
+        "\
+        {} file(s) should be formatted (nothing was modified)",
+        count.to_string().bright_red()
+    )
+}
+
+pub fn sources_formatted(count: usize) -> String {
+    format!("{} file(s) formatted", count)
+}
+
+pub fn sources_checked_for_format(count: usize) -> String {
+    format!("{} file(s) checked, none needs formatting", count)
+}
+
+pub fn javadoc_not_found() -> String {
+    format!(
+        "\
+        The `{}` tool (shipped with the JDK) couldn't be run.\n\
+        Make sure it's at your `{}`",
+        "javadoc".bright_red(),
+        "PATH".bright_cyan()
+    )
+}
+
+pub fn dokka_not_found(searched: &Path) -> String {
+    format!(
+        "\
+        Nothing was found at\n\
+        `{}`\n\
+        \n\
+        Kotlin docs are generated by the `{}`\n\
+        jar (and its `{}` plugin), taken from the local maven\n\
+        repository",
+        searched.to_string_lossy().bright_red(),
+        "org.jetbrains.dokka:dokka-cli".bright_cyan(),
+        "dokka-base".bright_cyan()
+    )
+}
+
+pub fn documentation_generated(doc_dir: &str) -> String {
+    format!(
+        "\
+        The html files are at the `{}` dir (open its\n\
+        `{}` file)",
+        doc_dir.bright_green(),
+        "index.html".bright_cyan()
+    )
+}
+
+pub fn library_cant_be_distributed(name: &str) -> String {
+    format!(
+        "\
+        `{}` is a library ({}), so there's no program to\n\
+        launch. Use `{}` to share its jar instead",
+        name.bright_red(),
+        "type = \"lib\"".bright_cyan(),
+        "kojamp install".bright_green()
+    )
+}
+
+pub fn dependency_left_out_of_dist(entry: &str) -> String {
+    format!(
+        "\
+        `{}` couldn't be copied into the `{}` dir, so\n\
+        the launchers won't find its classes",
+        entry.bright_red(),
+        "lib".bright_cyan()
+    )
+}
+
+pub fn distribution_created(archives: &[String], launchers: &[&str]) -> String {
+    let archives = archives
+        .iter()
+        .map(|a| format!("  {}", a.bright_green()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let launchers = launchers
+        .iter()
+        .map(|l| format!("`{}`", format!("bin/{}", l).bright_cyan()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "\
+        The archives were placed at\n\
+        {}\n\
+        \n\
+        Extract any of them and start the program with {}\n\
+        (or the `{}` files on windows)",
+        archives,
+        launchers,
+        ".bat".bright_cyan()
+    )
+}
+
+pub fn jdk_tool_not_found(tool: &str) -> String {
+    format!(
+        "\
+        Make sure the `{}` tool (from the JDK) is at your\n\
+        {} (or at the `{}` one)",
+        tool.bright_red(),
+        "PATH".bright_cyan(),
+        "JAVA_HOME".bright_cyan()
+    )
+}
+
+pub fn jdk_tool_failed(printed: &str) -> String {
+    format!(
+        "\
+        {}\n\
+        \n\
+        Runtime images are linked from the `{}` dir of the\n\
+        selected JDK (the `{}` one, otherwise the {} one)",
+        printed,
+        "jmods".bright_cyan(),
+        "JAVA_HOME".bright_cyan(),
+        "PATH".bright_cyan()
+    )
+}
+
+pub fn runtime_image_created(
+    image_dir: &str,
+    size: &str,
+    modules: &[String],
+    launchers: &[&str],
+) -> String {
+    let modules = modules
+        .iter()
+        .map(|m| format!("{}", m.bright_cyan()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let launchers = launchers
+        .iter()
+        .map(|l| {
+            format!("`{}`", format!("{}/bin/{}", image_dir, l).bright_green())
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
     format!(
         "\
-        Couldn't generate the output file path for\n\
-        `{}` name and `{}` kind.\n\
+        The `{}` dir ({}) holds the program and a runtime\n\
+        with the modules: {}\n\
         \n\
-        They're probably unvalid fields at `{}`",
-        name.bright_green(),
-        kind.bright_green(),
-        "Kojamp.toml".bright_green()
+        No installed java is needed to start it with {}",
+        image_dir.bright_green(),
+        size.bright_green(),
+        modules,
+        launchers
     )
 }
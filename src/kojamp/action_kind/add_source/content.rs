@@ -0,0 +1,40 @@
+use super::super::new_project::content::comment_section;
+
+fn java_declaration(source_kind: &str, name: &str) -> String {
+    match source_kind {
+        "interface" => format!("public interface {} {{\n\n}}", name),
+        "enum" => format!("public enum {} {{\n\n}}", name),
+        "record" => format!("public record {}() {{\n\n}}", name),
+        _ => format!("public class {} {{\n\n}}", name),
+    }
+}
+
+fn kotlin_declaration(source_kind: &str, name: &str) -> String {
+    match source_kind {
+        "interface" => format!("interface {} {{\n\n}}", name),
+        "enum" => format!("enum class {} {{\n\n}}", name),
+        "object" => format!("object {} {{\n\n}}", name),
+        "data-class" => {
+            format!("data class {}(\n    val value: String,\n)", name)
+        }
+        _ => format!("class {} {{\n\n}}", name),
+    }
+}
+
+pub fn source(
+    kind: &str,
+    source_kind: &str,
+    name: &str,
+    package: Option<&str>,
+) -> String {
+    let (declaration, semicolon) = match kind {
+        "java" => (java_declaration(source_kind, name), ";"),
+        _ => (kotlin_declaration(source_kind, name), ""),
+    };
+    let package_line = match package {
+        Some(p) => format!("package {}{}\n\n", p, semicolon),
+        None => String::new(),
+    };
+
+    format!("{}\n\n{}{}\n", comment_section(), package_line, declaration)
+}
@@ -0,0 +1,91 @@
+use super::{content, reports::*, utils::*};
+use crate::{
+    core::{
+        contracts::IsValid,
+        models::{ManifestError, ProjectManifest, ProjectName, UserConfig},
+        reporting::KojampReport,
+    },
+    kojamp::action::build_project::utils::item_is_here,
+};
+use clap::ArgMatches;
+use std::fs;
+
+const KOJAMP_TOML: &str = "Kojamp.toml";
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let (source_kind, matching) =
+        matching.subcommand().ok_or(vec![undefined_error()])?;
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+
+    match item_is_here(&curdir, KOJAMP_TOML) {
+        Ok(false) => Err(vec![kojamp_toml_is_missing()]),
+        Err(_) => Err(vec![could_not_read_dir()]),
+        _ => Ok(()),
+    }?;
+
+    let manifest = ProjectManifest::load(&curdir).map_err(|e| match e {
+        ManifestError::Invalid => vec![could_not_get_project_kind_from_toml()],
+        _ => vec![could_not_read_toml_file()],
+    })?;
+    let kind = manifest.get_kind();
+    let config = UserConfig::load().map_err(|_| vec![user_config_error()])?;
+
+    let name = matching.get_one::<String>("name").unwrap();
+    // the config prefix comes before the project package
+    let project_name = manifest.get_name();
+    let package = matching
+        .get_one::<String>("package")
+        .map(|p| p.to_string())
+        .or(config
+            .get_package_prefix()
+            .map(|p| prefixed_package(p, project_name)));
+
+    let mut errors: Vec<KojampReport> = Vec::new();
+
+    // camel cased names (A-Z first) can't be reserved words
+    if !ProjectName::from(name.as_str()).is_valid() {
+        errors.push(invalid_source_name(name));
+    }
+
+    if !source_kind_fits(kind, source_kind) {
+        errors.push(source_kind_mismatch(source_kind, kind));
+    }
+
+    if let Some(p) = &package {
+        if let Some(segment) = invalid_package_segment(kind, p) {
+            errors.push(invalid_package(p, segment));
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let path = source_path(kind, name, package.as_deref());
+
+    if path.exists() {
+        return Err(vec![source_already_exists(&path)]);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|_| vec![could_not_create_source(parent)])?;
+    }
+
+    fs::write(
+        &path,
+        content::source(kind, source_kind, name, package.as_deref()),
+    )
+    .map_err(|_| vec![could_not_create_source(&path)])?;
+
+    let mut output: Vec<KojampReport> = config
+        .get_ignored_env()
+        .iter()
+        .map(|(key, value)| ignored_env_override(key, value))
+        .collect();
+    output.push(success_report(name, &path));
+    Ok(output)
+}
@@ -0,0 +1,6 @@
+mod content;
+mod main;
+mod reports;
+mod utils;
+
+pub use main::main as add_source;
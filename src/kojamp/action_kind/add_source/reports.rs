@@ -0,0 +1,135 @@
+use crate::core::{
+    models::UserConfig,
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+use std::path::Path;
+
+const COULD_NOT_READ_PROJECT_FOLDER: &str = "Couldn't read project folder";
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const COULD_NOT_GET_PROJECT_KIND_FROM_TOML: &str =
+    "Couldn't get project kind from toml";
+const COULD_NOT_LOAD_USER_CONFIG: &str = "Couldn't load the user config";
+const IGNORED_ENV_OVERRIDE: &str = "Invalid environment variable";
+const INVALID_SOURCE_NAME: &str = "Invalid source name";
+const SOURCE_KIND_MISMATCH: &str = "Source kind not available";
+const INVALID_PACKAGE: &str = "Invalid package";
+const SOURCE_FILE_ALREADY_EXISTS: &str = "Source file already exists";
+const COULD_NOT_CREATE_SOURCE_FILE: &str = "Couldn't create the source file";
+const SOURCE_FILE_CREATED: &str = "`$$$` source created";
+const UNDEFINED_ERROR: &str = "Undefined error";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn kojamp_toml_is_missing() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        PROBABLY_A_NON_KOJAMP_PROJECT,
+        messages::kojamp_toml_not_found(),
+    )
+}
+
+pub fn could_not_read_dir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_READ_PROJECT_FOLDER,
+        messages::could_not_read_dir_content(),
+    )
+}
+
+pub fn could_not_read_toml_file() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_READ_TOML_FILE,
+        messages::toml_file_could_not_be_read(),
+    )
+}
+
+pub fn could_not_get_project_kind_from_toml() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_PROJECT_KIND_FROM_TOML,
+        messages::empty_message(),
+    )
+}
+
+pub fn user_config_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_LOAD_USER_CONFIG,
+        messages::user_config_could_not_be_loaded(),
+    )
+}
+
+pub fn invalid_source_name(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        INVALID_SOURCE_NAME,
+        messages::invalid_source_name(name),
+    )
+}
+
+pub fn source_kind_mismatch(source_kind: &str, kind: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        SOURCE_KIND_MISMATCH,
+        messages::source_kind_mismatch(source_kind, kind),
+    )
+}
+
+pub fn invalid_package(package: &str, segment: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        INVALID_PACKAGE,
+        messages::invalid_package(package, segment),
+    )
+}
+
+pub fn source_already_exists(path: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        SOURCE_FILE_ALREADY_EXISTS,
+        messages::source_file_already_exists(path),
+    )
+}
+
+pub fn could_not_create_source(path: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_CREATE_SOURCE_FILE,
+        messages::could_not_create_dir_file(&path.to_string_lossy()),
+    )
+}
+
+pub fn success_report(name: &str, path: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        SOURCE_FILE_CREATED.replace("$$$", &format!("{}", name.bright_green())),
+        messages::source_file_created(path),
+    )
+}
+
+pub fn undefined_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNDEFINED_ERROR,
+        messages::main_app_undefined_error(),
+    )
+}
+
+pub fn ignored_env_override(key: &str, value: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        IGNORED_ENV_OVERRIDE,
+        messages::ignored_env_override(&UserConfig::env_var(key), key, value),
+    )
+}
@@ -0,0 +1,57 @@
+use crate::core::consts::reserved_words::{
+    JAVA_RESERVED_WORDS, KOTLIN_RESERVED_WORDS,
+};
+use regex::Regex;
+use std::path::PathBuf;
+
+fn is_reserved(kind: &str, word: &str) -> bool {
+    match kind {
+        "java" => JAVA_RESERVED_WORDS.contains(&word),
+        _ => KOTLIN_RESERVED_WORDS.contains(&word),
+    }
+}
+
+pub fn source_kind_fits(kind: &str, source_kind: &str) -> bool {
+    match source_kind {
+        "record" => kind == "java",
+        "object" | "data-class" => kind == "kotlin",
+        _ => true,
+    }
+}
+
+// returns the first invalid segment (if any)
+pub fn invalid_package_segment<'a>(
+    kind: &str,
+    package: &'a str,
+) -> Option<&'a str> {
+    let segment = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap();
+    package
+        .split('.')
+        .find(|s| !segment.is_match(s) || is_reserved(kind, s))
+}
+
+// `com.example` + `MyApp` -> `com.example.myapp`
+pub fn prefixed_package(prefix: &str, project_name: &str) -> String {
+    let project_package: String = project_name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect::<String>()
+        .to_lowercase();
+    match project_package.is_empty() {
+        true => prefix.to_string(),
+        false => format!("{}.{}", prefix, project_package),
+    }
+}
+
+pub fn source_path(kind: &str, name: &str, package: Option<&str>) -> PathBuf {
+    let mut path = PathBuf::from("src");
+    if let Some(p) = package {
+        path.extend(p.split('.'));
+    }
+    path.push(name);
+    path.set_extension(match kind {
+        "java" => "java",
+        _ => "kt",
+    });
+    path
+}
@@ -0,0 +1,204 @@
+use super::reports::*;
+use crate::{
+    core::{models::ProjectManifest, reporting::KojampReport},
+    utils::{source::MODULE_INFO_FILE, time::UtcTime},
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+};
+
+const BUILD_INFO_CLASS: &str = "BuildInfo";
+
+fn is_valid_package(package: &str) -> bool {
+    package.split('.').all(|segment| {
+        let mut chars = segment.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+// the main class package, else the project group (libraries have no main
+// class), as the default package can't be imported from
+fn project_package(manifest: &ProjectManifest) -> Option<String> {
+    let package = match manifest.get_main_class().rsplit_once('.') {
+        Some((package, _)) => package.to_string(),
+        None => manifest.get_group(),
+    };
+    Some(package).filter(|p| is_valid_package(p))
+}
+
+// `yyyy-MM-ddTHH:mm:ssZ` (UTC)
+fn timestamp() -> String {
+    let now = UtcTime::now();
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        now.year, now.month, now.day, now.hour, now.minute, now.second
+    )
+}
+
+fn git(root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+// the commit hash (empty out of a git repo) and the dirty flag
+fn git_state(root: &Path) -> (String, bool) {
+    match git(root, &["rev-parse", "HEAD"]) {
+        Some(commit) => {
+            let status = git(root, &["status", "--porcelain"]);
+            (commit, status.is_some_and(|s| !s.is_empty()))
+        }
+        None => (String::new(), false),
+    }
+}
+
+fn quoted(value: &str, kind: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    match kind {
+        "kotlin" => format!("\"{}\"", escaped.replace('$', "\\$")),
+        _ => format!("\"{}\"", escaped),
+    }
+}
+
+struct BuildInfo {
+    name: String,
+    version: String,
+    authors: Vec<String>,
+    build_time: String,
+    commit: String,
+    dirty: bool,
+}
+
+fn java_source(package: &str, info: &BuildInfo) -> String {
+    let authors: Vec<String> =
+        info.authors.iter().map(|a| quoted(a, "java")).collect();
+    format!(
+        "\
+package {};
+
+// generated by kojamp (`generate-build-info`)
+public final class {} {{
+    public static final String NAME = {};
+    public static final String VERSION = {};
+    public static final java.util.List<String> AUTHORS =
+        java.util.Collections.unmodifiableList(java.util.Arrays.asList({}));
+    public static final String BUILD_TIME = {};
+    public static final String COMMIT = {};
+    public static final boolean DIRTY = {};
+
+    private {}() {{}}
+}}
+",
+        package,
+        BUILD_INFO_CLASS,
+        quoted(&info.name, "java"),
+        quoted(&info.version, "java"),
+        authors.join(", "),
+        quoted(&info.build_time, "java"),
+        quoted(&info.commit, "java"),
+        info.dirty,
+        BUILD_INFO_CLASS
+    )
+}
+
+fn kotlin_source(package: &str, info: &BuildInfo) -> String {
+    let authors: Vec<String> =
+        info.authors.iter().map(|a| quoted(a, "kotlin")).collect();
+    format!(
+        "\
+package {}
+
+// generated by kojamp (`generate-build-info`)
+object {} {{
+    const val NAME = {}
+    const val VERSION = {}
+    @JvmField val AUTHORS: List<String> = listOf({})
+    const val BUILD_TIME = {}
+    const val COMMIT = {}
+    const val DIRTY = {}
+}}
+",
+        package,
+        BUILD_INFO_CLASS,
+        quoted(&info.name, "kotlin"),
+        quoted(&info.version, "kotlin"),
+        authors.join(", "),
+        quoted(&info.build_time, "kotlin"),
+        quoted(&info.commit, "kotlin"),
+        info.dirty
+    )
+}
+
+// the generated dir (outside the project, so `src` is never touched)
+pub fn generated_dir() -> PathBuf {
+    env::temp_dir().join(format!("kojamp-build-info-{}", process::id()))
+}
+
+// the `BuildInfo` source file, compiled along with the project sources
+pub fn generate(
+    root: &Path,
+    manifest: &ProjectManifest,
+    sources: &[String],
+) -> Result<PathBuf, KojampReport> {
+    let descriptors = sources
+        .iter()
+        .filter(|s| Path::new(s).ends_with(MODULE_INFO_FILE))
+        .count();
+    if descriptors > 1 {
+        return Err(build_info_with_many_modules());
+    }
+
+    let package =
+        project_package(manifest).ok_or_else(build_info_without_package)?;
+    let kind = manifest.get_kind();
+    let extension = match kind {
+        "kotlin" => "kt",
+        _ => "java",
+    };
+    let mut relative = PathBuf::new();
+    relative.extend(package.split('.'));
+    relative.push(format!("{}.{}", BUILD_INFO_CLASS, extension));
+
+    // a class with the same name would clash with the generated one
+    if sources.iter().any(|s| Path::new(s).ends_with(&relative)) {
+        let class = format!("{}.{}", package, BUILD_INFO_CLASS);
+        return Err(build_info_already_defined(&class));
+    }
+
+    let (commit, dirty) = git_state(root);
+    let info = BuildInfo {
+        name: manifest.get_name().to_string(),
+        version: manifest.get_version().to_string(),
+        authors: manifest
+            .get_authors()
+            .iter()
+            .map(|a| a.to_string())
+            .collect(),
+        build_time: timestamp(),
+        commit,
+        dirty,
+    };
+    let content = match kind {
+        "kotlin" => kotlin_source(&package, &info),
+        _ => java_source(&package, &info),
+    };
+
+    let file = generated_dir().join(relative);
+    file.parent()
+        .map(fs::create_dir_all)
+        .transpose()
+        .and_then(|_| fs::write(&file, content))
+        .map_err(|_| could_not_generate_build_info(&file))?;
+    Ok(file)
+}
@@ -0,0 +1,140 @@
+use super::{build_info, main::KOJAMP_TOML, reports::*, utils::*};
+use crate::core::{
+    models::{ProjectGraph, ProjectManifest, ProjectWorkspace},
+    reporting::KojampReport,
+};
+use clap::ArgMatches;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+// the sources are compiled into a temp dir, so the `out` dir (used by
+// `run`) is never touched
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+
+    match item_is_here(&curdir, KOJAMP_TOML) {
+        Ok(false) => Err(vec![kojamp_toml_is_missing()]),
+        Err(_) => Err(vec![could_not_read_dir()]),
+        _ => Ok(()),
+    }?;
+
+    let package = matching.get_one::<String>("package");
+
+    // path dependencies are checked first (their outputs aren't trusted)
+    let (_, graph, targets) =
+        ProjectWorkspace::targets(&curdir, package.map(String::as_str))
+            .map_err(|e| vec![target_error(e)])?;
+    let targets = graph
+        .build_order_of(&targets)
+        .map_err(|e| vec![graph_error(e)])?;
+
+    let temp_dir =
+        env::temp_dir().join(format!("kojamp-check-{}", process::id()));
+    let output = check_targets(&graph, targets, &temp_dir);
+    let _ = fs::remove_dir_all(&temp_dir);
+    output
+}
+
+fn check_targets(
+    graph: &ProjectGraph,
+    targets: Vec<usize>,
+    temp_dir: &Path,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let mut output: Vec<KojampReport> = Vec::new();
+    let dest = |index: usize| temp_dir.join(index.to_string());
+
+    for index in targets {
+        let manifest = graph.get_node(index).get_manifest();
+        let (classpath, missing) = graph.classpath(index, dest);
+        output.extend(missing.iter().map(|m| missing_dependency(m)));
+
+        let root = graph.get_node(index).get_root();
+        match check_at(root, manifest, &dest(index), &classpath) {
+            Ok(report) => output.push(report),
+            Err(reports) => {
+                output.push(project_check_failed(manifest.get_name()));
+                output.extend(reports);
+                return Err(output);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+fn check_at(
+    root: &Path,
+    manifest: &ProjectManifest,
+    dest: &Path,
+    classpath: &[PathBuf],
+) -> Result<KojampReport, Vec<KojampReport>> {
+    let source_dir = manifest.get_source_dir();
+
+    if !root.join(source_dir).is_dir() {
+        return Err(vec![src_dir_is_missing(source_dir)]);
+    }
+
+    let name = manifest.get_name();
+    let kind = manifest.get_kind();
+
+    let src_files: Vec<String> = get_all_sources(kind, &root.join(source_dir))
+        .map_err(|p| vec![unreadable_src_content(source_dir, &p)])?
+        .into_iter()
+        .map(|f| f.to_string_lossy().to_string())
+        .collect();
+
+    if src_files.is_empty() {
+        return Err(vec![src_dir_is_empty(kind, source_dir)]);
+    }
+
+    let file_count = src_files.len();
+    let jvm_target = manifest.get_jvm_target();
+    fs::create_dir_all(dest)
+        .map_err(|_| vec![could_not_compile_the_sources()])?;
+
+    // the sources may use the generated `BuildInfo` class
+    let mut src_files = src_files;
+    if manifest.generate_build_info() {
+        let generated = build_info::generate(root, manifest, &src_files)
+            .map_err(|report| vec![report])?;
+        src_files.push(generated.to_string_lossy().to_string());
+    }
+
+    let checked =
+        run_check(src_files, kind, jvm_target.as_deref(), dest, classpath);
+    let _ = fs::remove_dir_all(build_info::generated_dir());
+    let result = checked.map_err(|_| vec![could_not_compile_the_sources()])?;
+    if result.status.success() {
+        return Ok(check_success_report(name.to_string(), file_count));
+    }
+
+    // each compiler error becomes a report (the raw output is the fallback)
+    let printed = format!(
+        "{}{}",
+        String::from_utf8_lossy(&result.stdout),
+        String::from_utf8_lossy(&result.stderr)
+    );
+    let errors: Vec<KojampReport> = diagnostics(&printed)
+        .into_iter()
+        .filter(|d| d.error)
+        .map(|d| {
+            let file = Path::new(&d.file);
+            let location =
+                file.strip_prefix(root).unwrap_or(file).to_string_lossy();
+            compile_error(&format!("{}:{}", location, d.line), &d.message)
+        })
+        .collect();
+    match errors.is_empty() {
+        true => {
+            eprint!("{}", printed);
+            Err(vec![could_not_compile_the_sources()])
+        }
+        false => Err(errors),
+    }
+}
@@ -1,12 +1,25 @@
-use super::{reports::*, utils::*};
-use crate::core::reporting::KojampReport;
-use std::{fs, path::PathBuf};
+use super::{build_info, reports::*, utils::*};
+use crate::{
+    core::{
+        models::{ProjectGraph, ProjectManifest, ProjectWorkspace},
+        reporting::KojampReport,
+    },
+    utils::source,
+};
+use clap::ArgMatches;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
 
-const KOJAMP_TOML: &str = "Kojamp.toml";
-const SRC_DIR: &str = "src";
+pub const KOJAMP_TOML: &str = "Kojamp.toml";
 
-pub fn main() -> Result<Vec<KojampReport>, Vec<KojampReport>> {
-    let curdir = std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
 
     match item_is_here(&curdir, KOJAMP_TOML) {
         Ok(false) => Err(vec![kojamp_toml_is_missing()]),
@@ -14,37 +27,75 @@ pub fn main() -> Result<Vec<KojampReport>, Vec<KojampReport>> {
         _ => Ok(()),
     }?;
 
-    match item_is_here(&curdir, SRC_DIR) {
-        Ok(false) => Err(vec![src_dir_is_missing()]),
-        Err(_) => Err(vec![could_not_read_dir()]),
-        _ => Ok(()),
-    }?;
+    let package = matching.get_one::<String>("package");
+
+    // a workspace builds every member (`--package` a single one), along
+    // with the path dependencies they need
+    let (workspace, graph, targets) =
+        ProjectWorkspace::targets(&curdir, package.map(String::as_str))
+            .map_err(|e| vec![target_error(e)])?;
+    let targets = graph
+        .build_order_of(&targets)
+        .map_err(|e| vec![graph_error(e)])?;
+
+    build_targets(&graph, targets, |i| graph.out_dir(i, workspace.as_ref()))
+}
+
+// path dependencies are built first and then placed at the classpath
+fn build_targets<F: Fn(usize) -> PathBuf>(
+    graph: &ProjectGraph,
+    targets: Vec<usize>,
+    out_dir: F,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let mut output: Vec<KojampReport> = Vec::new();
 
-    let toml_content = fs::read_to_string(curdir.join(KOJAMP_TOML))
-        .map_err(|_| vec![could_not_read_toml_file()])?;
+    for index in targets {
+        let manifest = graph.get_node(index).get_manifest();
+        let (classpath, missing) = graph.classpath(index, |d| {
+            let dep = graph.get_node(d).get_manifest();
+            output_entry(&out_dir(d), dep.get_name(), dep.get_kind())
+        });
+        output.extend(missing.iter().map(|m| missing_dependency(m)));
+
+        let root = graph.get_node(index).get_root();
+        match build_at(root, manifest, &out_dir(index), &classpath) {
+            Ok(reports) => output.extend(reports),
+            Err(reports) => {
+                output.push(project_build_failed(manifest.get_name()));
+                output.extend(reports);
+                return Err(output);
+            }
+        }
+    }
 
-    let mut errors: Vec<KojampReport> = Vec::new();
+    Ok(output)
+}
 
-    let name = get_project_name_from_toml(&toml_content)
-        .map_err(|_| errors.push(could_not_get_project_name_from_toml()));
-    let kind = get_project_kind_from_toml(&toml_content)
-        .map_err(|_| errors.push(could_not_get_project_kind_from_toml()));
+// builds the project at `root` into `out_dir` (`classpath` holds the
+// already built dependencies)
+pub fn build_at(
+    root: &Path,
+    manifest: &ProjectManifest,
+    out_dir: &Path,
+    classpath: &[PathBuf],
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let source_dir = manifest.get_source_dir();
 
-    if !errors.is_empty() {
-        return Err(errors);
+    if !root.join(source_dir).is_dir() {
+        return Err(vec![src_dir_is_missing(source_dir)]);
     }
 
-    let kind = kind.unwrap();
-    let name = name.unwrap();
-    let src_files = get_all_sources(&kind, &PathBuf::from("src"))
-        .map_err(|p| vec![unreadable_src_content(&p)])?;
+    let kind = manifest.get_kind().to_string();
+    let name = manifest.get_name().to_string();
+    let src_files = get_all_sources(&kind, &root.join(source_dir))
+        .map_err(|p| vec![unreadable_src_content(source_dir, &p)])?;
     let src_files: Vec<String> = src_files
         .into_iter()
         .map(|f| f.to_string_lossy().to_string())
         .collect();
 
     if src_files.is_empty() {
-        return Err(vec![src_dir_is_empty(&kind)]);
+        return Err(vec![src_dir_is_empty(&kind, source_dir)]);
     }
 
     let file_names: Vec<String> = src_files
@@ -57,23 +108,150 @@ pub fn main() -> Result<Vec<KojampReport>, Vec<KojampReport>> {
         })
         .collect();
 
-    let main_file = {
-        let mut as_pbf = PathBuf::from(&name);
-        as_pbf.set_extension(match kind.as_ref() {
-            "java" => "java",
-            _ => "kt",
-        });
-        as_pbf.to_string_lossy().to_string()
+    // libraries have no main file
+    let library = manifest.is_library();
+    let main_class = manifest.get_main_class();
+    let main_file = main_file_name(&main_class, &kind);
+    let modules = match kind.as_str() {
+        "java" => module_names(&root.join(source_dir)),
+        _ => Vec::new(),
     };
+    if library && modules.len() > 1 {
+        return Err(vec![library_with_many_modules(&name)]);
+    }
+
+    // every `[[bin]]` entry is checked after compiling instead
+    let bins = manifest.get_bins();
+    if !library && bins.is_empty() {
+        let sources: Vec<PathBuf> =
+            src_files.iter().map(PathBuf::from).collect();
+        let mains = source::find_mains(&kind, &sources);
+        if !mains.iter().any(|(_, m)| m.class == main_class) {
+            let suggestion = source::suggested_main(&mains, &name);
+            return Err(vec![match file_names.contains(&main_file) {
+                true => {
+                    main_method_not_found(&main_class, &main_file, suggestion)
+                }
+                false => main_project_file_is_not_present(
+                    main_file, source_dir, suggestion,
+                ),
+            }]);
+        }
+    }
+
+    let jvm_target = manifest.get_jvm_target();
+    let dest = output_entry(out_dir, &name, &kind);
 
-    if !file_names.contains(&main_file) {
-        return Err(vec![main_project_file_is_not_present(main_file)]);
+    let mut src_files = src_files;
+    if manifest.generate_build_info() {
+        let generated = build_info::generate(root, manifest, &src_files)
+            .map_err(|report| vec![report])?;
+        src_files.push(generated.to_string_lossy().to_string());
     }
 
-    match run_build(&name, src_files, &kind) {
+    let built = run_build(
+        src_files,
+        &kind,
+        jvm_target.as_deref(),
+        &dest,
+        classpath,
+        library,
+    );
+    let _ = fs::remove_dir_all(build_info::generated_dir());
+    match built {
         Ok(true) => {}
         _ => return Err(vec![could_not_compile_the_sources()]),
     }
 
-    Ok(vec![success_report(name, file_names.len())])
+    let mut output = Vec::new();
+
+    // resources land next to the classes (or into the kotlin jar)
+    let resources_dir = manifest.get_resources_dir();
+    if root.join(resources_dir).is_dir() {
+        // with many modules, each one takes the `resources/<module>` dir
+        if modules.len() > 1 {
+            let outside = root
+                .join(resources_dir)
+                .read_dir()
+                .into_iter()
+                .flatten()
+                .filter_map(|e| e.ok())
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .filter(|n| !modules.contains(n));
+            output.extend(
+                outside.map(|n| resource_outside_modules(&n, &modules)),
+            );
+        }
+        let staging = match kind.as_str() {
+            "java" => out_dir.to_path_buf(),
+            _ => env::temp_dir()
+                .join(format!("kojamp-resources-{}", process::id())),
+        };
+        let copied = copy_resources(
+            &root.join(resources_dir),
+            &staging,
+            &manifest.get_filtered_resources(),
+            &name,
+            manifest.get_version(),
+        );
+        let added = match (kind.as_str(), &copied) {
+            ("java", _) | (_, Err(_)) => Ok(()),
+            _ => add_to_jar(&dest, &staging),
+        };
+        if kind != "java" {
+            let _ = fs::remove_dir_all(&staging);
+        }
+
+        match (copied, added) {
+            (Ok(issues), Ok(())) => {
+                output.extend(issues.iter().map(|i| match i {
+                    ResourceIssue::Missing(file) => {
+                        filtered_resource_not_found(file, resources_dir)
+                    }
+                    ResourceIssue::NotText(file) => {
+                        filtered_resource_not_text(file)
+                    }
+                }))
+            }
+            (Err(file), _) => return Err(vec![could_not_copy_resource(&file)]),
+            (_, Err(())) => return Err(vec![could_not_copy_resource(&dest)]),
+        }
+    }
+
+    if kind == "kotlin" {
+        let authors: Vec<String> = manifest
+            .get_authors()
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        if write_jar_manifest(&dest, &name, &authors).is_err() {
+            output.push(could_not_write_jar_manifest(&dest));
+        }
+    }
+
+    let missing_bins: Vec<KojampReport> = bins
+        .iter()
+        .filter(|bin| !class_is_built(&dest, &kind, bin.get_main_class()))
+        .map(|bin| bin_class_not_found(bin.get_name(), bin.get_main_class()))
+        .collect();
+    if !missing_bins.is_empty() {
+        return Err(missing_bins);
+    }
+
+    if library {
+        let jar = out_dir.join(manifest.get_library_jar());
+        let _ = fs::remove_file(&jar);
+        let packed = match kind.as_str() {
+            "java" => pack_classes(out_dir, &jar, None),
+            _ => fs::copy(&dest, &jar).map(|_| ()).map_err(|_| ()),
+        };
+        if packed.is_err() {
+            return Err(vec![could_not_pack_library_jar(&jar)]);
+        }
+        output.push(library_success_report(name, file_names.len(), &jar));
+        return Ok(output);
+    }
+
+    output.push(success_report(name, file_names.len()));
+    Ok(output)
 }
@@ -1,5 +1,8 @@
-mod main;
+mod build_info;
+mod check;
+pub mod main;
 mod reports;
-mod utils;
+pub mod utils;
 
+pub use check::main as check_project;
 pub use main::main as build_project;
@@ -1,21 +1,49 @@
 use colored::Colorize;
 use std::path::Path;
 
-use crate::core::reporting::{messages, KojampReport, ReportType};
+use crate::core::{
+    models::{GraphError, ManifestError, TargetError},
+    reporting::{messages, KojampReport, ReportType},
+};
 
 const COULD_NOT_READ_PROJECT_FOLDER: &str = "Couldn't read project folder";
-const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str = "Couldn't get the current directory";
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
 const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
-const COULD_NOT_FIND_SRC_DIR: &str = "Couldn't find src dir";
 const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
-const COULD_NOT_GET_PROJECT_NAME_FROM_TOML: &str = "Couldn't get project name from toml";
-const COULD_NOT_GET_PROJECT_KIND_FROM_TOML: &str = "Couldn't get project kind from toml";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const COULD_NOT_FIND_SRC_DIR: &str = "Couldn't find `$$$` dir";
 const UNREADABLE_SRC_CONTENT: &str = "Unreadable src content";
 const THERES_NO_FILES_FOR_THE_GIVEN_PROJECT_KIND: &str =
     "There's no files for the given project kind";
-const MAIN_PROJECT_FILE_IS_NOT_PRESENT: &str = "Main project file isn't present in src dir";
+const MAIN_PROJECT_FILE_IS_NOT_PRESENT: &str =
+    "Main project file isn't present in `$$$` dir";
 const COULD_NOT_COMPILE_THE_SOURCES: &str = "Couldn't compile the source code";
-const PROGRAM_SUCCESSFULLY_COMPILED: &str = "The `$$$` program was successfully compiled";
+const COULD_NOT_WRITE_JAR_MANIFEST: &str = "Couldn't write the jar manifest";
+const COULD_NOT_COPY_RESOURCE: &str = "Couldn't copy the resources";
+const FILTERED_RESOURCE_NOT_FOUND: &str = "Filtered resource not found";
+const FILTERED_RESOURCE_NOT_TEXT: &str = "Resource `$$$` wasn't filtered";
+const NOT_A_WORKSPACE: &str = "Not a workspace";
+const UNKNOWN_MEMBER: &str = "Unknown workspace member";
+const INVALID_PROJECT_DEPENDENCY: &str = "Invalid project at `$$$`";
+const DEPENDENCY_CYCLE: &str = "Dependency cycle";
+const PROJECT_BUILD_FAILED: &str = "Couldn't build the `$$$` project";
+const MISSING_DEPENDENCY: &str = "Missing dependency";
+const PROGRAM_SUCCESSFULLY_COMPILED: &str =
+    "The `$$$` program was successfully compiled";
+const LIBRARY_SUCCESSFULLY_COMPILED: &str =
+    "The `$$$` library was successfully compiled";
+const COULD_NOT_PACK_LIBRARY_JAR: &str = "Couldn't pack the library jar";
+const LIBRARY_WITH_MANY_MODULES: &str = "Libraries can't have many modules";
+const RESOURCE_OUTSIDE_MODULES: &str = "Resource `$$$` is outside the modules";
+const PROJECT_CHECK_FAILED: &str = "The `$$$` project has errors";
+const COMPILE_ERROR: &str = "Compile error at `$$$`";
+const PROJECT_SUCCESSFULLY_CHECKED: &str = "The `$$$` project has no errors";
+const BIN_CLASS_NOT_FOUND: &str = "The `$$$` entry class wasn't built";
+const MAIN_METHOD_NOT_FOUND: &str = "The main class has no main method";
+const BUILD_INFO_NOT_GENERATED: &str = "Couldn't generate the build info";
+const BUILD_INFO_ALREADY_DEFINED: &str = "The `$$$` class already exists";
+const COULD_NOT_GENERATE_BUILD_INFO: &str = "Couldn't write `$$$`";
 
 pub fn could_not_get_curdir() -> KojampReport {
     KojampReport::new(
@@ -41,74 +69,298 @@ pub fn could_not_read_dir() -> KojampReport {
     )
 }
 
-pub fn src_dir_is_missing() -> KojampReport {
+pub fn src_dir_is_missing(source_dir: &str) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_FIND_SRC_DIR,
+        COULD_NOT_FIND_SRC_DIR.replace("$$$", source_dir),
         messages::empty_message(),
     )
 }
 
-pub fn could_not_read_toml_file() -> KojampReport {
+pub fn unreadable_src_content(source_dir: &str, path: &Path) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_READ_TOML_FILE,
-        messages::toml_file_could_not_be_read(),
+        UNREADABLE_SRC_CONTENT,
+        messages::unreadable_src_content(source_dir, path),
     )
 }
 
-pub fn could_not_get_project_name_from_toml() -> KojampReport {
+pub fn src_dir_is_empty(kind: &str, source_dir: &str) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_GET_PROJECT_NAME_FROM_TOML,
-        messages::empty_message(),
+        THERES_NO_FILES_FOR_THE_GIVEN_PROJECT_KIND,
+        messages::theres_no_files_for_the_given_project_kind(kind, source_dir),
+    )
+}
+
+pub fn main_project_file_is_not_present(
+    file_name: String,
+    source_dir: &str,
+    suggestion: Option<&str>,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        MAIN_PROJECT_FILE_IS_NOT_PRESENT.replace("$$$", source_dir),
+        messages::main_project_file_is_not_present(file_name, suggestion),
     )
 }
 
-pub fn could_not_get_project_kind_from_toml() -> KojampReport {
+pub fn main_method_not_found(
+    main_class: &str,
+    file_name: &str,
+    suggestion: Option<&str>,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        MAIN_METHOD_NOT_FOUND,
+        messages::main_method_not_found(main_class, file_name, suggestion),
+    )
+}
+
+pub fn could_not_compile_the_sources() -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_GET_PROJECT_KIND_FROM_TOML,
+        COULD_NOT_COMPILE_THE_SOURCES,
         messages::empty_message(),
     )
 }
 
-pub fn unreadable_src_content(path: &Path) -> KojampReport {
+pub fn could_not_write_jar_manifest(jar: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        COULD_NOT_WRITE_JAR_MANIFEST,
+        messages::could_not_write_jar_manifest(jar),
+    )
+}
+
+pub fn could_not_copy_resource(path: &Path) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        UNREADABLE_SRC_CONTENT,
-        messages::unreadable_src_content(path),
+        COULD_NOT_COPY_RESOURCE,
+        messages::could_not_copy_resource(path),
     )
 }
 
-pub fn src_dir_is_empty(kind: &str) -> KojampReport {
+pub fn filtered_resource_not_found(
+    file: &str,
+    resources_dir: &str,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        FILTERED_RESOURCE_NOT_FOUND,
+        messages::filtered_resource_not_found(file, resources_dir),
+    )
+}
+
+pub fn filtered_resource_not_text(file: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        FILTERED_RESOURCE_NOT_TEXT.replace("$$$", file),
+        messages::filtered_resource_not_text(),
+    )
+}
+
+pub fn success_report(name: String, file_count: usize) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        PROGRAM_SUCCESSFULLY_COMPILED
+            .replace("$$$", &format!("{}", name.bright_green())),
+        messages::successfully_compiled(file_count),
+    )
+}
+
+pub fn library_success_report(
+    name: String,
+    file_count: usize,
+    jar: &Path,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        LIBRARY_SUCCESSFULLY_COMPILED
+            .replace("$$$", &format!("{}", name.bright_green())),
+        messages::library_successfully_compiled(
+            file_count,
+            &std::env::current_dir()
+                .ok()
+                .and_then(|c| jar.strip_prefix(c).ok())
+                .unwrap_or(jar)
+                .to_string_lossy(),
+        ),
+    )
+}
+
+pub fn could_not_pack_library_jar(jar: &Path) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        THERES_NO_FILES_FOR_THE_GIVEN_PROJECT_KIND,
-        messages::theres_no_files_for_the_given_project_kind(kind),
+        COULD_NOT_PACK_LIBRARY_JAR,
+        messages::could_not_pack_jar(&jar.to_string_lossy()),
     )
 }
 
-pub fn main_project_file_is_not_present(file_name: String) -> KojampReport {
+pub fn library_with_many_modules(name: &str) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        MAIN_PROJECT_FILE_IS_NOT_PRESENT,
-        messages::main_project_file_is_not_present(file_name),
+        LIBRARY_WITH_MANY_MODULES,
+        messages::project_with_many_modules(name),
     )
 }
 
-pub fn could_not_compile_the_sources() -> KojampReport {
+pub fn resource_outside_modules(
+    entry: &str,
+    modules: &[String],
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        RESOURCE_OUTSIDE_MODULES.replace("$$$", entry),
+        messages::resource_outside_modules(modules),
+    )
+}
+
+pub fn bin_class_not_found(bin: &str, main_class: &str) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_COMPILE_THE_SOURCES,
+        BIN_CLASS_NOT_FOUND.replace("$$$", &format!("{}", bin.bright_red())),
+        messages::bin_class_not_found(main_class),
+    )
+}
+
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => KojampReport::new(
+            ReportType::Error,
+            PROBABLY_A_NON_KOJAMP_PROJECT,
+            messages::kojamp_toml_not_found(),
+        ),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn not_a_workspace() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NOT_A_WORKSPACE,
+        messages::not_a_workspace(),
+    )
+}
+
+pub fn unknown_member(name: &str, members: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNKNOWN_MEMBER,
+        messages::unknown_workspace_member(name, members),
+    )
+}
+
+pub fn graph_error(error: GraphError) -> KojampReport {
+    match error {
+        GraphError::Manifest(path, e) => KojampReport::new(
+            ReportType::Error,
+            INVALID_PROJECT_DEPENDENCY.replace("$$$", &path.to_string_lossy()),
+            match e {
+                ManifestError::Missing => messages::kojamp_toml_not_found(),
+                ManifestError::Unreadable => {
+                    messages::toml_file_could_not_be_read()
+                }
+                ManifestError::Invalid => messages::invalid_toml_file(),
+            },
+        ),
+        GraphError::Cycle(cycle) => KojampReport::new(
+            ReportType::Error,
+            DEPENDENCY_CYCLE,
+            messages::dependency_cycle(&cycle),
+        ),
+    }
+}
+
+pub fn target_error(error: TargetError) -> KojampReport {
+    match error {
+        TargetError::Manifest(e) => manifest_error(e),
+        TargetError::NotAWorkspace => not_a_workspace(),
+        TargetError::Graph(e) => graph_error(e),
+        TargetError::UnknownMember(name, members) => {
+            unknown_member(&name, &members)
+        }
+    }
+}
+
+pub fn missing_dependency(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        MISSING_DEPENDENCY,
+        messages::missing_dependency(name),
+    )
+}
+
+pub fn project_build_failed(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        PROJECT_BUILD_FAILED.replace("$$$", &format!("{}", name.bright_red())),
         messages::empty_message(),
     )
 }
 
-pub fn success_report(name: String, file_count: usize) -> KojampReport {
+pub fn project_check_failed(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        PROJECT_CHECK_FAILED.replace("$$$", &format!("{}", name.bright_red())),
+        messages::empty_message(),
+    )
+}
+
+pub fn compile_error(location: &str, message: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COMPILE_ERROR.replace("$$$", location),
+        message,
+    )
+}
+
+pub fn check_success_report(name: String, file_count: usize) -> KojampReport {
     KojampReport::new(
         ReportType::Success,
-        PROGRAM_SUCCESSFULLY_COMPILED.replace("$$$", &format!("{}", name.bright_green())),
-        messages::successfully_compiled(file_count),
+        PROJECT_SUCCESSFULLY_CHECKED
+            .replace("$$$", &format!("{}", name.bright_green())),
+        messages::successfully_checked(file_count),
+    )
+}
+
+pub fn build_info_with_many_modules() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        BUILD_INFO_NOT_GENERATED,
+        messages::build_info_with_many_modules(),
+    )
+}
+
+pub fn build_info_without_package() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        BUILD_INFO_NOT_GENERATED,
+        messages::build_info_without_package(),
+    )
+}
+
+pub fn build_info_already_defined(class: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        BUILD_INFO_ALREADY_DEFINED.replace("$$$", class),
+        messages::build_info_already_defined(),
+    )
+}
+
+pub fn could_not_generate_build_info(file: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GENERATE_BUILD_INFO.replace("$$$", &file.to_string_lossy()),
+        messages::could_not_write_to_repository(&file.to_string_lossy()),
     )
 }
@@ -1,10 +1,17 @@
+use crate::{
+    core::consts::files_and_dirs::{JAR_FILE_EXTENSION, OUT_TOOL_DIRS},
+    utils::source::{self, MODULE_INFO_FILE},
+};
+use regex::Regex;
 use std::{
-    env, fs,
+    env,
+    ffi::OsString,
+    fs,
     path::{Path, PathBuf},
-    process::Command,
+    process::{self, Command, Output, Stdio},
 };
 
-pub fn item_is_here<T: AsRef<str>>(path: &PathBuf, file: T) -> Result<bool, ()> {
+pub fn item_is_here<T: AsRef<str>>(path: &Path, file: T) -> Result<bool, ()> {
     Ok(fs::read_dir(path)
         .map_err(|_| ())?
         .filter_map(|entry| entry.ok())
@@ -17,21 +24,22 @@ pub fn item_is_here<T: AsRef<str>>(path: &PathBuf, file: T) -> Result<bool, ()>
         }))
 }
 
-pub fn get_project_name_from_toml(file_input: &str) -> Result<String, ()> {
-    let binding = file_input.parse::<toml::Value>().map_err(|_| ())?;
-    let project_table = binding.get("project").ok_or(())?;
-    let name_field = project_table.get("name").ok_or(())?.as_str().ok_or(())?;
-    Ok(String::from(name_field))
-}
-
-pub fn get_project_kind_from_toml(file_input: &str) -> Result<String, ()> {
-    let binding = file_input.parse::<toml::Value>().map_err(|_| ())?;
-    let project_table = binding.get("project").ok_or(())?;
-    let kind_field = project_table.get("kind").ok_or(())?.as_str().ok_or(())?;
-    Ok(String::from(kind_field))
+// `com.foo.App` lives at `App.java`, while `com.foo.AppKt` at `App.kt`
+pub fn main_file_name(main_class: &str, kind: &str) -> String {
+    let simple_name = main_class.rsplit('.').next().unwrap_or(main_class);
+    match kind {
+        "java" => format!("{}.java", simple_name),
+        _ => format!(
+            "{}.kt",
+            simple_name.strip_suffix("Kt").unwrap_or(simple_name)
+        ),
+    }
 }
 
-pub fn get_all_sources<T: AsRef<str>>(kind: T, init_path: &Path) -> Result<Vec<PathBuf>, PathBuf> {
+pub fn get_all_sources<T: AsRef<str>>(
+    kind: T,
+    init_path: &Path,
+) -> Result<Vec<PathBuf>, PathBuf> {
     let ext_target = match kind.as_ref() {
         "java" => "java",
         _ => "kt",
@@ -47,12 +55,14 @@ pub fn get_all_sources<T: AsRef<str>>(kind: T, init_path: &Path) -> Result<Vec<P
             let nested_path = abs.file_name().ok_or(&abs)?;
             let nested_path = init_path.join(nested_path);
             let nested_files = get_all_sources(kind.as_ref(), &nested_path)?;
-            output.extend(nested_files.into_iter());
+            output.extend(nested_files);
         }
         let f_name = abs.file_name().ok_or(&abs)?;
         match f_name.to_str() {
             None => return Err(PathBuf::from(f_name)),
-            Some(x) if x.ends_with(ext_target) => output.push(init_path.join(x)),
+            Some(x) if x.ends_with(ext_target) => {
+                output.push(init_path.join(x))
+            }
             _ => {}
         }
     }
@@ -60,34 +70,371 @@ pub fn get_all_sources<T: AsRef<str>>(kind: T, init_path: &Path) -> Result<Vec<P
     Ok(output)
 }
 
-pub fn run_build(name: &str, sources: Vec<String>, kind: &str) -> Result<bool, Vec<String>> {
+// many modules are built at `out/<module>` (a single one right at `out`), so
+// they can't be packed into a single jar
+pub fn module_names(source_dir: &Path) -> Vec<String> {
+    source::find_modules(source_dir)
+        .into_iter()
+        .map(|(_, m)| m.name)
+        .collect()
+}
+
+// java classes go to the out dir, while kotlin ones are packed in a jar
+pub fn output_entry(out_dir: &Path, name: &str, kind: &str) -> PathBuf {
+    match kind {
+        "java" => out_dir.to_path_buf(),
+        _ => out_dir.join(format!("{}.jar", name)),
+    }
+}
+
+// jars and built modules (a `module-info.class` at the dir or at its
+// module dirs) go to the module path, while plain class dirs can't
+pub fn is_module_path_entry(entry: &Path) -> bool {
+    let has_descriptor = |dir: &Path| dir.join("module-info.class").is_file();
+    match entry.is_dir() {
+        true => {
+            has_descriptor(entry)
+                || entry
+                    .read_dir()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|e| e.ok())
+                    .any(|e| has_descriptor(&e.path()))
+        }
+        false => entry.extension().is_some_and(|e| e == JAR_FILE_EXTENSION),
+    }
+}
+
+// a single module is built right at `dest`, while a source dir with many
+// module dirs goes by `--module-source-path` (each one at `dest/<module>`)
+fn module_args(
+    sources: &[String],
+    classpath: &[PathBuf],
+) -> Option<Vec<OsString>> {
+    let descriptors: Vec<&Path> = sources
+        .iter()
+        .map(Path::new)
+        .filter(|s| s.file_name().is_some_and(|n| n == MODULE_INFO_FILE))
+        .collect();
+    if descriptors.is_empty() {
+        return None;
+    }
+
+    let mut args: Vec<OsString> = Vec::new();
+    if let [first, _, ..] = descriptors.as_slice() {
+        let source_dir = first
+            .parent()
+            .and_then(Path::parent)
+            .unwrap_or(Path::new("."));
+        args.push("--module-source-path".into());
+        args.push(source_dir.into());
+    }
+    let (modules, classes): (Vec<&PathBuf>, Vec<&PathBuf>) =
+        classpath.iter().partition(|e| is_module_path_entry(e));
+    for (flag, entries) in
+        [("--module-path", modules), ("--class-path", classes)]
+    {
+        match env::join_paths(entries) {
+            Ok(joined) if !joined.is_empty() => {
+                args.extend([flag.into(), joined])
+            }
+            _ => {}
+        }
+    }
+    Some(args)
+}
+
+fn compiler_command(
+    sources: &[String],
+    kind: &str,
+    jvm_target: Option<&str>,
+    dest: &Path,
+    classpath: &[PathBuf],
+    library: bool,
+    check: bool,
+) -> Option<Command> {
     let pathenv: String = env::var("PATH").unwrap_or_default();
-    let (cmd_name, dest) = match kind {
-        "java" => (String::from("javac"), String::from("out")),
-        _ => (
-            {
-                let mut cmd = String::from("kotlinc");
-                if cfg!(target_os = "windows") {
-                    cmd.push_str(".bat");
-                }
-                cmd
-            },
-            format!("out/{}.jar", name),
-        ),
+    let cmd_name = match kind {
+        "java" => String::from("javac"),
+        _ => {
+            let mut cmd = String::from("kotlinc");
+            if cfg!(target_os = "windows") {
+                cmd.push_str(".bat");
+            }
+            cmd
+        }
     };
 
-    let mut args = Vec::new();
-    if kind == "kotlin" {
-        args.push("-include-runtime");
+    let mut command = Command::new(cmd_name);
+    command.env("PATH", pathenv);
+    // libraries leave the kotlin runtime to the projects using them
+    if kind == "kotlin" && !library && !check {
+        command.arg("-include-runtime");
+    }
+    // checks only care about errors (no annotation processing either)
+    if check {
+        match kind {
+            "java" => command.args(["-proc:none", "-implicit:none", "-nowarn"]),
+            _ => command.arg("-nowarn"),
+        };
+    }
+    if let Some(target) = jvm_target {
+        command.arg(if kind == "java" {
+            "--release"
+        } else {
+            "-jvm-target"
+        });
+        command.arg(target);
+    }
+    let module_args =
+        module_args(sources, classpath).filter(|_| kind == "java");
+    if let Some(args) = module_args {
+        command.args(args);
+    } else if !classpath.is_empty() {
+        let joined = env::join_paths(classpath).ok()?;
+        command.arg(if kind == "java" {
+            "--class-path"
+        } else {
+            "-classpath"
+        });
+        command.arg(joined);
     }
-    args.extend(sources.iter().map(|s| s.as_str()));
-    args.push("-d");
-    args.push(&dest);
+    if let Some(parent) = dest.parent().filter(|_| kind == "kotlin") {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    command.args(sources.iter()).arg("-d").arg(dest);
+    Some(command)
+}
+
+pub fn run_build(
+    sources: Vec<String>,
+    kind: &str,
+    jvm_target: Option<&str>,
+    dest: &Path,
+    classpath: &[PathBuf],
+    library: bool,
+) -> Result<bool, Vec<String>> {
+    compiler_command(
+        &sources, kind, jvm_target, dest, classpath, library, false,
+    )
+    .and_then(|mut command| command.status().ok())
+    .map(|x| x.success())
+    .ok_or(sources)
+}
+
+// the compiler output is captured, so its errors can be reported one by one
+pub fn run_check(
+    sources: Vec<String>,
+    kind: &str,
+    jvm_target: Option<&str>,
+    dest: &Path,
+    classpath: &[PathBuf],
+) -> Result<Output, Vec<String>> {
+    compiler_command(&sources, kind, jvm_target, dest, classpath, false, true)
+        .and_then(|mut command| command.output().ok())
+        .ok_or(sources)
+}
+
+pub struct Diagnostic {
+    pub file: String,
+    pub line: usize,
+    pub error: bool,
+    pub message: String,
+}
 
-    Command::new(cmd_name)
-        .env("PATH", pathenv)
-        .args(args)
-        .status()
-        .map(|x| x.success())
-        .map_err(|_| sources)
+// `File.java:12: warning: no comment` lines (kotlinc adds the column, and
+// the source excerpt that follows each one is left out)
+pub fn diagnostics(output: &str) -> Vec<Diagnostic> {
+    let pattern =
+        Regex::new(r"^(.+?):(\d+)(?::\d+)?: (warning|error): (.+)$").unwrap();
+    output
+        .lines()
+        .filter_map(|line| pattern.captures(line))
+        .map(|c| Diagnostic {
+            file: c[1].to_string(),
+            line: c[2].parse().unwrap_or_default(),
+            error: &c[3] == "error",
+            message: c[4].to_string(),
+        })
+        .collect()
+}
+
+pub fn write_jar_manifest(
+    jar: &Path,
+    name: &str,
+    authors: &[String],
+) -> Result<(), ()> {
+    let mut manifest = format!("Implementation-Title: {}\n", name);
+    if !authors.is_empty() {
+        manifest.push_str(&format!(
+            "Implementation-Vendor: {}\n",
+            authors.join(", ")
+        ));
+    }
+
+    let manifest_path =
+        env::temp_dir().join(format!("kojamp-{}.mf", process::id()));
+    fs::write(&manifest_path, manifest).map_err(|_| ())?;
+
+    let status = Command::new("jar")
+        .arg("--update")
+        .arg("--file")
+        .arg(jar)
+        .arg("--manifest")
+        .arg(&manifest_path)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    let _ = fs::remove_file(&manifest_path);
+
+    match status {
+        Ok(s) if s.success() => Ok(()),
+        _ => Err(()),
+    }
+}
+
+// the compiled classes (jars previously placed at the out dir are left out),
+// runnable when a main class is given
+pub fn pack_classes(
+    classes_dir: &Path,
+    jar: &Path,
+    main_class: Option<&str>,
+) -> Result<(), ()> {
+    let mut command = Command::new("jar");
+    command.arg("--create").arg("--file").arg(jar);
+    if let Some(m) = main_class {
+        command.arg("--main-class").arg(m);
+    }
+
+    let entries = classes_dir.read_dir().map_err(|_| ())?;
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.is_file()
+            && path.extension().is_some_and(|e| e == JAR_FILE_EXTENSION)
+            || OUT_TOOL_DIRS.iter().any(|d| entry.file_name() == *d)
+        {
+            continue;
+        }
+        command.arg("-C").arg(classes_dir).arg(entry.file_name());
+    }
+
+    match command.stdout(Stdio::null()).stderr(Stdio::null()).status() {
+        Ok(s) if s.success() => Ok(()),
+        _ => Err(()),
+    }
+}
+
+// `com.foo.App` is built as `com/foo/App.class` (at the out dir or the jar)
+pub fn class_is_built(dest: &Path, kind: &str, main_class: &str) -> bool {
+    let class_file = format!("{}.class", main_class.replace('.', "/"));
+    match kind {
+        // modules built by `--module-source-path` are at `dest/<module>`
+        "java" => {
+            dest.join(&class_file).is_file()
+                || dest
+                    .read_dir()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|e| e.ok())
+                    .any(|e| e.path().join(&class_file).is_file())
+        }
+        _ => fs::File::open(dest)
+            .ok()
+            .and_then(|f| zip::ZipArchive::new(f).ok())
+            .is_some_and(|a| a.index_for_name(&class_file).is_some()),
+    }
+}
+
+pub enum ResourceIssue {
+    // a `filter-resources` entry with no file
+    Missing(String),
+    // a `filter-resources` file that isn't text (copied as is)
+    NotText(String),
+}
+
+// every file under `resources` copied into `target` (the `filtered` ones
+// with their `${project.name}`/`${project.version}` placeholders replaced)
+pub fn copy_resources(
+    resources: &Path,
+    target: &Path,
+    filtered: &[String],
+    name: &str,
+    version: &str,
+) -> Result<Vec<ResourceIssue>, PathBuf> {
+    fn walk(
+        dir: &Path,
+        prefix: &str,
+        output: &mut Vec<(String, PathBuf)>,
+    ) -> Result<(), PathBuf> {
+        for entry in dir.read_dir().map_err(|_| dir.to_path_buf())? {
+            let path = entry.map_err(|_| dir.to_path_buf())?.path();
+            let relative = format!(
+                "{}{}",
+                prefix,
+                path.file_name().unwrap_or_default().to_string_lossy()
+            );
+            match path.is_dir() {
+                true => walk(&path, &format!("{}/", relative), output)?,
+                false => output.push((relative, path)),
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    walk(resources, "", &mut files)?;
+
+    let mut issues = Vec::new();
+    for (relative, path) in files.iter() {
+        let destination = target.join(relative);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).map_err(|_| parent.to_path_buf())?;
+        }
+        let content = match filtered.contains(relative) {
+            true => fs::read_to_string(path).ok(),
+            false => None,
+        };
+        match content {
+            Some(text) => fs::write(
+                &destination,
+                text.replace("${project.name}", name)
+                    .replace("${project.version}", version),
+            ),
+            None => {
+                if filtered.contains(relative) {
+                    issues.push(ResourceIssue::NotText(relative.clone()));
+                }
+                fs::copy(path, &destination).map(|_| ())
+            }
+        }
+        .map_err(|_| destination.clone())?;
+    }
+
+    issues.extend(
+        filtered
+            .iter()
+            .filter(|f| !files.iter().any(|(relative, _)| relative == *f))
+            .map(|f| ResourceIssue::Missing(f.clone())),
+    );
+    Ok(issues)
+}
+
+// kotlin builds are jars, so the resources are added to them
+pub fn add_to_jar(jar: &Path, dir: &Path) -> Result<(), ()> {
+    let status = Command::new("jar")
+        .arg("--update")
+        .arg("--file")
+        .arg(jar)
+        .arg("-C")
+        .arg(dir)
+        .arg(".")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    match status {
+        Ok(s) if s.success() => Ok(()),
+        _ => Err(()),
+    }
 }
@@ -0,0 +1,146 @@
+use super::{reports::*, utils::*};
+use crate::{
+    core::{models::ProjectWorkspace, reporting::KojampReport},
+    kojamp::action::build_project::utils::{diagnostics, output_entry},
+    utils::{path::display, source},
+};
+use clap::ArgMatches;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const DOC_DIR: &str = "doc";
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let package = matching.get_one::<String>("package");
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+
+    let (workspace, graph, targets) =
+        ProjectWorkspace::targets(&curdir, package.map(String::as_str))
+            .map_err(|e| vec![target_error(e)])?;
+
+    let out_dir = |index: usize| graph.out_dir(index, workspace.as_ref());
+
+    let mut output: Vec<KojampReport> = Vec::new();
+    for index in targets {
+        let node = graph.get_node(index);
+        let manifest = node.get_manifest();
+        // path dependencies are taken from their last build
+        let (classpath, missing) = graph.classpath(index, |d| {
+            let dep = graph.get_node(d).get_manifest();
+            output_entry(&out_dir(d), dep.get_name(), dep.get_kind())
+        });
+        output.extend(missing.iter().map(|m| missing_dependency(m)));
+
+        let source_dir = manifest.get_source_dir();
+        let root = node.get_root();
+        if !root.join(source_dir).is_dir() {
+            output.push(src_dir_is_missing(source_dir));
+            return Err(output);
+        }
+
+        let doc_dir = out_dir(index).join(DOC_DIR);
+        let _ = fs::remove_dir_all(&doc_dir);
+
+        let documented = match manifest.get_kind() {
+            "java" => javadoc(
+                root,
+                source_dir,
+                &classpath,
+                &doc_dir,
+                &curdir,
+                &mut output,
+            ),
+            _ => dokka(
+                root,
+                manifest.get_name(),
+                source_dir,
+                &classpath,
+                &doc_dir,
+            ),
+        };
+
+        match documented {
+            Ok(()) => output.push(success_report(
+                manifest.get_name(),
+                &display(&doc_dir, &curdir),
+            )),
+            Err(report) => {
+                output.push(report);
+                return Err(output);
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+// javadoc comment problems don't stop the html generation, so they are
+// warnings when the docs come out (and errors otherwise)
+fn javadoc(
+    root: &Path,
+    source_dir: &str,
+    classpath: &[PathBuf],
+    doc_dir: &Path,
+    base: &Path,
+    output: &mut Vec<KojampReport>,
+) -> Result<(), KojampReport> {
+    let sources = source::collect_sources("java", &root.join(source_dir))
+        .map_err(|p| unreadable_src_content(source_dir, &p))?;
+    if sources.is_empty() {
+        return Err(src_dir_is_empty("java", source_dir));
+    }
+
+    let result =
+        run_javadoc(&sources, &root.join(source_dir), classpath, doc_dir)
+            .ok_or(javadoc_not_found())?;
+    let printed = format!(
+        "{}{}",
+        String::from_utf8_lossy(&result.stdout),
+        String::from_utf8_lossy(&result.stderr)
+    );
+    let generated = doc_dir.join("index.html").is_file();
+
+    for diagnostic in diagnostics(&printed) {
+        let location = format!(
+            "{}:{}",
+            display(Path::new(&diagnostic.file), base),
+            diagnostic.line
+        );
+        output.push(match diagnostic.error && !generated {
+            true => javadoc_error(&location, &diagnostic.message),
+            false => javadoc_warning(&location, &diagnostic.message),
+        });
+    }
+
+    match generated {
+        true => Ok(()),
+        false => Err(documentation_failed()),
+    }
+}
+
+fn dokka(
+    root: &Path,
+    name: &str,
+    source_dir: &str,
+    classpath: &[PathBuf],
+    doc_dir: &Path,
+) -> Result<(), KojampReport> {
+    let (cli, plugins) = dokka_jars().map_err(|p| dokka_not_found(&p))?;
+
+    match run_dokka(
+        &cli,
+        &plugins,
+        name,
+        &root.join(source_dir),
+        classpath,
+        doc_dir,
+    ) {
+        Some(true) => Ok(()),
+        _ => Err(documentation_failed()),
+    }
+}
@@ -0,0 +1,5 @@
+mod main;
+mod reports;
+mod utils;
+
+pub use main::main as document_project;
@@ -0,0 +1,184 @@
+use crate::core::{
+    models::{GraphError, ManifestError, TargetError},
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+use std::path::Path;
+
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const NOT_A_WORKSPACE: &str = "Not a workspace";
+const UNKNOWN_MEMBER: &str = "Unknown workspace member";
+const INVALID_PROJECT_DEPENDENCY: &str = "Invalid project at `$$$`";
+const DEPENDENCY_CYCLE: &str = "Dependency cycle";
+const MISSING_DEPENDENCY: &str = "Missing dependency";
+const COULD_NOT_FIND_SRC_DIR: &str = "Couldn't find `$$$` dir";
+const UNREADABLE_SRC_CONTENT: &str = "Unreadable src content";
+const THERES_NO_FILES_FOR_THE_GIVEN_PROJECT_KIND: &str =
+    "There's no files for the given project kind";
+const JAVADOC_NOT_FOUND: &str = "Couldn't run javadoc";
+const DOKKA_NOT_FOUND: &str = "Couldn't find the dokka jars";
+const JAVADOC_WARNING: &str = "Javadoc warning at `$$$`";
+const JAVADOC_ERROR: &str = "Javadoc error at `$$$`";
+const DOCUMENTATION_FAILED: &str = "Couldn't generate the documentation";
+const DOCUMENTATION_GENERATED: &str = "The `$$$` documentation was generated";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => KojampReport::new(
+            ReportType::Error,
+            PROBABLY_A_NON_KOJAMP_PROJECT,
+            messages::kojamp_toml_not_found(),
+        ),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn not_a_workspace() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NOT_A_WORKSPACE,
+        messages::not_a_workspace(),
+    )
+}
+
+pub fn unknown_member(name: &str, members: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNKNOWN_MEMBER,
+        messages::unknown_workspace_member(name, members),
+    )
+}
+
+pub fn target_error(error: TargetError) -> KojampReport {
+    match error {
+        TargetError::Manifest(e) => manifest_error(e),
+        TargetError::NotAWorkspace => not_a_workspace(),
+        TargetError::Graph(e) => graph_error(e),
+        TargetError::UnknownMember(name, members) => {
+            unknown_member(&name, &members)
+        }
+    }
+}
+
+pub fn graph_error(error: GraphError) -> KojampReport {
+    match error {
+        GraphError::Manifest(path, e) => KojampReport::new(
+            ReportType::Error,
+            INVALID_PROJECT_DEPENDENCY.replace("$$$", &path.to_string_lossy()),
+            match e {
+                ManifestError::Missing => messages::kojamp_toml_not_found(),
+                ManifestError::Unreadable => {
+                    messages::toml_file_could_not_be_read()
+                }
+                ManifestError::Invalid => messages::invalid_toml_file(),
+            },
+        ),
+        GraphError::Cycle(cycle) => KojampReport::new(
+            ReportType::Error,
+            DEPENDENCY_CYCLE,
+            messages::dependency_cycle(&cycle),
+        ),
+    }
+}
+
+pub fn missing_dependency(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        MISSING_DEPENDENCY,
+        messages::missing_dependency(name),
+    )
+}
+
+pub fn src_dir_is_missing(source_dir: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_FIND_SRC_DIR.replace("$$$", source_dir),
+        messages::empty_message(),
+    )
+}
+
+pub fn unreadable_src_content(source_dir: &str, path: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNREADABLE_SRC_CONTENT,
+        messages::unreadable_src_content(source_dir, path),
+    )
+}
+
+pub fn src_dir_is_empty(kind: &str, source_dir: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        THERES_NO_FILES_FOR_THE_GIVEN_PROJECT_KIND,
+        messages::theres_no_files_for_the_given_project_kind(kind, source_dir),
+    )
+}
+
+pub fn javadoc_not_found() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        JAVADOC_NOT_FOUND,
+        messages::javadoc_not_found(),
+    )
+}
+
+pub fn dokka_not_found(searched: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        DOKKA_NOT_FOUND,
+        messages::dokka_not_found(searched),
+    )
+}
+
+pub fn javadoc_warning(location: &str, message: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        JAVADOC_WARNING.replace("$$$", location),
+        message,
+    )
+}
+
+pub fn javadoc_error(location: &str, message: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        JAVADOC_ERROR.replace("$$$", location),
+        message,
+    )
+}
+
+pub fn documentation_failed() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        DOCUMENTATION_FAILED,
+        messages::empty_message(),
+    )
+}
+
+pub fn success_report(name: &str, doc_dir: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        DOCUMENTATION_GENERATED
+            .replace("$$$", &format!("{}", name.bright_green())),
+        messages::documentation_generated(doc_dir),
+    )
+}
@@ -0,0 +1,103 @@
+use crate::core::{
+    consts::files_and_dirs::JAR_FILE_EXTENSION,
+    models::{DependencyTree, ProjectDependency},
+};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+const DOKKA_GROUP: &str = "org.jetbrains.dokka";
+const DOKKA_CLI: &str = "dokka-cli";
+// the html output and the kotlin analysis (older versions have no analysis
+// artifact, so its absence is ignored)
+const DOKKA_PLUGINS: [&str; 2] = ["dokka-base", "analysis-kotlin-descriptors"];
+
+pub fn run_javadoc(
+    sources: &[PathBuf],
+    source_dir: &Path,
+    classpath: &[PathBuf],
+    doc_dir: &Path,
+) -> Option<Output> {
+    let mut command = Command::new("javadoc");
+    command
+        .arg("-quiet")
+        .arg("-d")
+        .arg(doc_dir)
+        .arg("-sourcepath")
+        .arg(source_dir);
+    if !classpath.is_empty() {
+        command
+            .arg("-classpath")
+            .arg(env::join_paths(classpath).ok()?);
+    }
+    command
+        .args(sources)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .ok()
+}
+
+// the latest dokka cli and its plugins (with their dependencies) at the
+// maven repository (`Err` holds the searched location)
+pub fn dokka_jars() -> Result<(PathBuf, Vec<PathBuf>), PathBuf> {
+    let (cli_dir, version) =
+        ProjectDependency::latest_installed(DOKKA_GROUP, DOKKA_CLI)?;
+    let cli = cli_dir
+        .join(format!("{}-{}.{}", DOKKA_CLI, version, JAR_FILE_EXTENSION));
+    if !cli.is_file() {
+        return Err(cli);
+    }
+
+    let mut plugins: Vec<PathBuf> = Vec::new();
+    for plugin in DOKKA_PLUGINS {
+        let tree =
+            DependencyTree::resolve_artifact(DOKKA_GROUP, plugin, &version);
+        for jar in tree.jars() {
+            if !plugins.contains(&jar) {
+                plugins.push(jar);
+            }
+        }
+    }
+
+    Ok((cli, plugins))
+}
+
+// dokka joins its classpath entries with `;` (at every platform)
+pub fn run_dokka(
+    cli: &Path,
+    plugins: &[PathBuf],
+    name: &str,
+    source_dir: &Path,
+    classpath: &[PathBuf],
+    doc_dir: &Path,
+) -> Option<bool> {
+    let join = |paths: &[PathBuf]| {
+        paths
+            .iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join(";")
+    };
+    let mut source_set = format!("-src {}", source_dir.to_string_lossy());
+    if !classpath.is_empty() {
+        source_set.push_str(&format!(" -classpath {}", join(classpath)));
+    }
+
+    Command::new("java")
+        .arg("-jar")
+        .arg(cli)
+        .arg("-moduleName")
+        .arg(name)
+        .arg("-outputDir")
+        .arg(doc_dir)
+        .arg("-sourceSet")
+        .arg(source_set)
+        .arg("-pluginsClasspath")
+        .arg(join(plugins))
+        .status()
+        .ok()
+        .map(|s| s.success())
+}
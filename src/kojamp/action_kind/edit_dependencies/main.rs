@@ -0,0 +1,87 @@
+use super::{reports::*, utils::*};
+use crate::{
+    core::{models::ProjectDependency, reporting::KojampReport},
+    kojamp::action::build_project::utils::item_is_here,
+};
+use clap::ArgMatches;
+use std::{fs, path::Path};
+use toml_edit::DocumentMut;
+
+const KOJAMP_TOML: &str = "Kojamp.toml";
+
+fn load_manifest(curdir: &Path) -> Result<DocumentMut, Vec<KojampReport>> {
+    match item_is_here(curdir, KOJAMP_TOML) {
+        Ok(false) => Err(vec![kojamp_toml_is_missing()]),
+        Err(_) => Err(vec![could_not_read_dir()]),
+        _ => Ok(()),
+    }?;
+
+    fs::read_to_string(curdir.join(KOJAMP_TOML))
+        .map_err(|_| vec![could_not_read_toml_file()])?
+        .parse::<DocumentMut>()
+        .map_err(|_| vec![invalid_toml_file()])
+}
+
+pub fn add(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let matching = matching
+        .subcommand_matches("dep")
+        .ok_or(vec![undefined_error()])?;
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+    let mut document = load_manifest(&curdir)?;
+
+    let spec = matching.get_one::<String>("dependency").unwrap();
+    let dependency = ProjectDependency::from(spec.as_str());
+    let name = matching
+        .get_one::<String>("name")
+        .cloned()
+        .or(dependency.default_name())
+        .ok_or(vec![could_not_name_dependency(spec)])?;
+
+    // nothing is written for dependencies that can't be found locally
+    if dependency.local_path(&curdir).is_none() {
+        return Err(vec![unresolved_dependency(
+            spec,
+            dependency.is_maven(),
+            dependency.expected_path(&curdir).as_deref(),
+        )]);
+    }
+
+    let table = dependencies_table(&mut document)
+        .map_err(|_| vec![invalid_toml_file()])?;
+    let replaced = table.contains_key(&name);
+    table.insert(&name, toml_edit::value(dependency.to_toml_value()));
+
+    write_document(&curdir.join(KOJAMP_TOML), &document)
+        .map_err(|_| vec![could_not_write_toml_file()])?;
+
+    Ok(vec![dependency_added(&name, replaced)])
+}
+
+pub fn remove(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let matching = matching
+        .subcommand_matches("dep")
+        .ok_or(vec![undefined_error()])?;
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+    let mut document = load_manifest(&curdir)?;
+
+    let name = matching.get_one::<String>("name").unwrap();
+    let removed = document
+        .get_mut(DEPENDENCIES_TABLE)
+        .and_then(|t| t.as_table_like_mut())
+        .and_then(|t| t.remove(name));
+
+    if removed.is_none() {
+        return Err(vec![dependency_not_found(name)]);
+    }
+
+    write_document(&curdir.join(KOJAMP_TOML), &document)
+        .map_err(|_| vec![could_not_write_toml_file()])?;
+
+    Ok(vec![dependency_removed(name)])
+}
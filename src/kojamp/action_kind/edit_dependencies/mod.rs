@@ -0,0 +1,5 @@
+mod main;
+mod reports;
+mod utils;
+
+pub use main::{add as add_dependency, remove as remove_dependency};
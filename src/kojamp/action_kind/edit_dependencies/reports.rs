@@ -0,0 +1,123 @@
+use crate::core::reporting::{messages, KojampReport, ReportType};
+use colored::Colorize;
+use std::path::Path;
+
+const COULD_NOT_READ_PROJECT_FOLDER: &str = "Couldn't read project folder";
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const COULD_NOT_WRITE_TOML_FILE: &str = "Couldn't write toml file";
+const COULD_NOT_NAME_DEPENDENCY: &str = "Couldn't name the dependency";
+const UNRESOLVED_DEPENDENCY: &str = "Unresolved dependency";
+const DEPENDENCY_NOT_FOUND: &str = "Dependency not found";
+const DEPENDENCY_ADDED: &str = "`$$$` dependency added";
+const DEPENDENCY_UPDATED: &str = "`$$$` dependency updated";
+const DEPENDENCY_REMOVED: &str = "`$$$` dependency removed";
+const UNDEFINED_ERROR: &str = "Undefined error";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn kojamp_toml_is_missing() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        PROBABLY_A_NON_KOJAMP_PROJECT,
+        messages::kojamp_toml_not_found(),
+    )
+}
+
+pub fn could_not_read_dir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_READ_PROJECT_FOLDER,
+        messages::could_not_read_dir_content(),
+    )
+}
+
+pub fn could_not_read_toml_file() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_READ_TOML_FILE,
+        messages::toml_file_could_not_be_read(),
+    )
+}
+
+pub fn invalid_toml_file() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        INVALID_TOML_FILE,
+        messages::invalid_toml_file(),
+    )
+}
+
+pub fn could_not_write_toml_file() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_WRITE_TOML_FILE,
+        messages::could_not_create_dir_file("Kojamp.toml"),
+    )
+}
+
+pub fn could_not_name_dependency(spec: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_NAME_DEPENDENCY,
+        messages::could_not_name_dependency(spec),
+    )
+}
+
+pub fn unresolved_dependency(
+    spec: &str,
+    is_maven: bool,
+    expected: Option<&Path>,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNRESOLVED_DEPENDENCY,
+        messages::unresolved_dependency(spec, is_maven, expected),
+    )
+}
+
+pub fn dependency_not_found(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        DEPENDENCY_NOT_FOUND,
+        messages::dependency_not_found(name),
+    )
+}
+
+pub fn dependency_added(name: &str, replaced: bool) -> KojampReport {
+    let title = if replaced {
+        DEPENDENCY_UPDATED
+    } else {
+        DEPENDENCY_ADDED
+    };
+    KojampReport::new(
+        ReportType::Success,
+        title.replace("$$$", &format!("{}", name.bright_green())),
+        messages::empty_message(),
+    )
+}
+
+pub fn dependency_removed(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        DEPENDENCY_REMOVED.replace("$$$", &format!("{}", name.bright_green())),
+        messages::empty_message(),
+    )
+}
+
+pub fn undefined_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNDEFINED_ERROR,
+        messages::main_app_undefined_error(),
+    )
+}
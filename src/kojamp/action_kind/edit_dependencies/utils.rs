@@ -0,0 +1,22 @@
+use std::{fs, path::Path};
+use toml_edit::DocumentMut;
+
+pub const DEPENDENCIES_TABLE: &str = "dependencies";
+
+// the `[dependencies]` table is created (at the end) when missing
+pub fn dependencies_table(
+    document: &mut DocumentMut,
+) -> Result<&mut toml_edit::Table, ()> {
+    if !document.contains_key(DEPENDENCIES_TABLE) {
+        document.insert(DEPENDENCIES_TABLE, toml_edit::table());
+    }
+    document[DEPENDENCIES_TABLE].as_table_mut().ok_or(())
+}
+
+pub fn write_document(path: &Path, document: &DocumentMut) -> Result<(), ()> {
+    let mut content = document.to_string();
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    fs::write(path, content).map_err(|_| ())
+}
@@ -0,0 +1,309 @@
+use crate::core::{consts::program::PROGRAM_REPO_URL, models::ProjectManifest};
+
+pub const KOTLIN_VERSION: &str = "2.0.21";
+pub const DEFAULT_JVM_TARGET: &str = "17";
+
+// dependencies already translated from the `[dependencies]` table
+pub enum ExportedDependency {
+    Maven {
+        group: String,
+        artifact: String,
+        version: String,
+    },
+    Jar {
+        name: String,
+        path: String,
+    },
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn gradle_settings(manifest: &ProjectManifest) -> String {
+    format!(
+        "// Generated by `kojamp export gradle` ({})\n\nrootProject.name = \"{}\"\n",
+        PROGRAM_REPO_URL,
+        manifest.get_artifact()
+    )
+}
+
+pub fn gradle_build(
+    manifest: &ProjectManifest,
+    dependencies: &[ExportedDependency],
+) -> String {
+    let kotlin = manifest.get_kind() == "kotlin";
+    let jvm_target = manifest
+        .get_jvm_target()
+        .unwrap_or(DEFAULT_JVM_TARGET.to_string());
+
+    // libraries have no main class to run
+    let (plugin, application) = match manifest.is_library() {
+        true => (String::from("`java-library`"), String::new()),
+        false => (
+            String::from("application"),
+            format!(
+                "\napplication {{\n    mainClass.set(\"{}\")\n}}\n",
+                manifest.get_main_class()
+            ),
+        ),
+    };
+    let plugins = if kotlin {
+        format!(
+            "    kotlin(\"jvm\") version \"{}\"\n    {}",
+            KOTLIN_VERSION, plugin
+        )
+    } else {
+        format!("    {}", plugin)
+    };
+
+    let dependency_rows: String = dependencies
+        .iter()
+        .map(|dep| match dep {
+            ExportedDependency::Maven {
+                group,
+                artifact,
+                version,
+            } => format!(
+                "    implementation(\"{}:{}:{}\")\n",
+                group, artifact, version
+            ),
+            ExportedDependency::Jar { path, .. } => {
+                format!("    implementation(files(\"{}\"))\n", path)
+            }
+        })
+        .collect();
+
+    let source_dir = manifest.get_source_dir();
+    let source_dirs = if kotlin {
+        format!(
+            "        kotlin.setSrcDirs(listOf(\"{}\"))\n        java.setSrcDirs(listOf(\"{}\"))",
+            source_dir, source_dir
+        )
+    } else {
+        format!("        java.setSrcDirs(listOf(\"{}\"))", source_dir)
+    };
+
+    let target = if kotlin {
+        format!(
+            "kotlin {{\n    compilerOptions {{\n        jvmTarget.set(org.jetbrains.kotlin.gradle.dsl.JvmTarget.fromTarget(\"{}\"))\n    }}\n}}\n\n\
+            tasks.withType<JavaCompile> {{\n    options.release.set({})\n}}",
+            jvm_target, jvm_target
+        )
+    } else {
+        format!(
+            "tasks.withType<JavaCompile> {{\n    options.release.set({})\n}}",
+            jvm_target
+        )
+    };
+
+    format!(
+        r#"// Generated by `kojamp export gradle` ({})
+
+plugins {{
+{}
+}}
+
+group = "{}"
+version = "{}"
+
+repositories {{
+    mavenLocal()
+    mavenCentral()
+}}
+
+dependencies {{
+{}}}
+
+// kojamp keeps every source at a single flat dir
+sourceSets {{
+    main {{
+{}
+    }}
+}}
+
+{}
+{}"#,
+        PROGRAM_REPO_URL,
+        plugins,
+        manifest.get_group(),
+        manifest.get_version(),
+        dependency_rows,
+        source_dirs,
+        target,
+        application
+    )
+}
+
+fn maven_dependency(
+    group: &str,
+    artifact: &str,
+    version: &str,
+    extra: &str,
+) -> String {
+    format!(
+        "    <dependency>\n      <groupId>{}</groupId>\n      <artifactId>{}</artifactId>\n      <version>{}</version>\n{}    </dependency>\n",
+        xml_escape(group),
+        xml_escape(artifact),
+        xml_escape(version),
+        extra
+    )
+}
+
+pub fn maven_pom(
+    manifest: &ProjectManifest,
+    dependencies: &[ExportedDependency],
+) -> String {
+    let kotlin = manifest.get_kind() == "kotlin";
+    let jvm_target = manifest
+        .get_jvm_target()
+        .unwrap_or(DEFAULT_JVM_TARGET.to_string());
+
+    let mut dependency_rows = String::new();
+    if kotlin {
+        dependency_rows.push_str(&maven_dependency(
+            "org.jetbrains.kotlin",
+            "kotlin-stdlib",
+            "${kotlin.version}",
+            "",
+        ));
+    }
+    for dep in dependencies {
+        match dep {
+            ExportedDependency::Maven {
+                group,
+                artifact,
+                version,
+            } => dependency_rows.push_str(&maven_dependency(group, artifact, version, "")),
+            ExportedDependency::Jar { name, path } => dependency_rows.push_str(&maven_dependency(
+                "local",
+                name,
+                "0",
+                &format!(
+                    "      <scope>system</scope>\n      <systemPath>${{project.basedir}}/{}</systemPath>\n",
+                    xml_escape(path)
+                ),
+            )),
+        }
+    }
+
+    let developers: String = manifest
+        .get_authors()
+        .iter()
+        .map(|a| {
+            let email = a
+                .get_email()
+                .map(|e| format!("      <email>{}</email>\n", xml_escape(e)))
+                .unwrap_or_default();
+            format!(
+                "    <developer>\n      <name>{}</name>\n{}    </developer>\n",
+                xml_escape(a.get_name()),
+                email
+            )
+        })
+        .collect();
+    let developers = if developers.is_empty() {
+        developers
+    } else {
+        format!("  <developers>\n{}  </developers>\n\n", developers)
+    };
+
+    let kotlin_property = if kotlin {
+        format!("    <kotlin.version>{}</kotlin.version>\n", KOTLIN_VERSION)
+    } else {
+        String::new()
+    };
+    let kotlin_plugin = if kotlin {
+        format!(
+            r#"      <plugin>
+        <groupId>org.jetbrains.kotlin</groupId>
+        <artifactId>kotlin-maven-plugin</artifactId>
+        <version>${{kotlin.version}}</version>
+        <configuration>
+          <jvmTarget>{}</jvmTarget>
+        </configuration>
+        <executions>
+          <execution>
+            <id>compile</id>
+            <goals>
+              <goal>compile</goal>
+            </goals>
+          </execution>
+        </executions>
+      </plugin>
+"#,
+            jvm_target
+        )
+    } else {
+        String::new()
+    };
+
+    // libraries have no main class to run
+    let jar_plugin = if manifest.is_library() {
+        String::new()
+    } else {
+        format!(
+            r#"      <plugin>
+        <groupId>org.apache.maven.plugins</groupId>
+        <artifactId>maven-jar-plugin</artifactId>
+        <configuration>
+          <archive>
+            <manifest>
+              <mainClass>{}</mainClass>
+            </manifest>
+          </archive>
+        </configuration>
+      </plugin>
+"#,
+            xml_escape(&manifest.get_main_class())
+        )
+    };
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Generated by `kojamp export maven` ({}) -->
+<project xmlns="http://maven.apache.org/POM/4.0.0"
+         xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+         xsi:schemaLocation="http://maven.apache.org/POM/4.0.0 https://maven.apache.org/xsd/maven-4.0.0.xsd">
+  <modelVersion>4.0.0</modelVersion>
+
+  <groupId>{}</groupId>
+  <artifactId>{}</artifactId>
+  <version>{}</version>
+  <packaging>jar</packaging>
+  <name>{}</name>
+
+{}  <properties>
+    <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>
+    <maven.compiler.release>{}</maven.compiler.release>
+{}  </properties>
+
+  <dependencies>
+{}  </dependencies>
+
+  <build>
+    <!-- kojamp keeps every source at a single flat dir -->
+    <sourceDirectory>{}</sourceDirectory>
+    <plugins>
+{}{}    </plugins>
+  </build>
+</project>
+"#,
+        PROGRAM_REPO_URL,
+        xml_escape(&manifest.get_group()),
+        xml_escape(&manifest.get_artifact()),
+        xml_escape(manifest.get_version()),
+        xml_escape(manifest.get_name()),
+        developers,
+        jvm_target,
+        kotlin_property,
+        dependency_rows,
+        xml_escape(manifest.get_source_dir()),
+        kotlin_plugin,
+        jar_plugin
+    )
+}
@@ -0,0 +1,111 @@
+use super::{
+    content::{self, ExportedDependency},
+    reports::*,
+};
+use crate::core::{
+    consts::files_and_dirs::JAR_FILE_EXTENSION,
+    models::{ProjectDependency, ProjectManifest},
+    reporting::KojampReport,
+};
+use clap::ArgMatches;
+use std::fs;
+
+const GRADLE_SETTINGS_FILE: &str = "settings.gradle.kts";
+const GRADLE_BUILD_FILE: &str = "build.gradle.kts";
+const MAVEN_POM_FILE: &str = "pom.xml";
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let tool = matching.get_one::<String>("tool").unwrap().as_str();
+    let force = matching.get_flag("force");
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+    let manifest =
+        ProjectManifest::load(&curdir).map_err(|e| vec![manifest_error(e)])?;
+
+    let mut output: Vec<KojampReport> = Vec::new();
+    let mut dependencies: Vec<ExportedDependency> = Vec::new();
+
+    for entry in manifest.get_dependencies() {
+        let (name, path) = match entry {
+            Err(name) => {
+                output.push(invalid_dependency_entry(&name));
+                continue;
+            }
+            Ok((
+                _,
+                ProjectDependency::Maven {
+                    group,
+                    artifact,
+                    version,
+                },
+            )) => {
+                dependencies.push(ExportedDependency::Maven {
+                    group,
+                    artifact,
+                    version,
+                });
+                continue;
+            }
+            Ok((name, ProjectDependency::Path(p))) => (name, p),
+        };
+
+        let as_str = path.to_string_lossy().replace('\\', "/");
+
+        if path.extension().is_some_and(|e| e == JAR_FILE_EXTENSION) {
+            dependencies.push(ExportedDependency::Jar { name, path: as_str });
+            continue;
+        }
+
+        // other kojamp projects have no gradle build script to include, while
+        // maven can consume them from the local repository
+        match (tool, ProjectManifest::load(&curdir.join(&path))) {
+            ("gradle", _) => output.push(project_dependency_left_out(&name)),
+            (_, Ok(dep_manifest)) => {
+                output.push(project_dependency_note(&name));
+                dependencies.push(ExportedDependency::Maven {
+                    group: dep_manifest.get_group(),
+                    artifact: dep_manifest.get_artifact(),
+                    version: dep_manifest.get_version().to_string(),
+                });
+            }
+            (_, Err(_)) => output.push(untranslated_dependency(&name)),
+        }
+    }
+
+    let files: Vec<(&str, String)> = match tool {
+        "gradle" => vec![
+            (GRADLE_SETTINGS_FILE, content::gradle_settings(&manifest)),
+            (
+                GRADLE_BUILD_FILE,
+                content::gradle_build(&manifest, &dependencies),
+            ),
+        ],
+        _ => {
+            vec![(MAVEN_POM_FILE, content::maven_pom(&manifest, &dependencies))]
+        }
+    };
+
+    let existing: Vec<&str> = files
+        .iter()
+        .map(|(f, _)| *f)
+        .filter(|f| curdir.join(f).exists())
+        .collect();
+
+    if !force && !existing.is_empty() {
+        return Err(vec![build_files_already_exist(&existing)]);
+    }
+
+    for (file, content) in files.iter() {
+        fs::write(curdir.join(file), content)
+            .map_err(|_| vec![could_not_write_file(file)])?;
+    }
+
+    output.push(success_report(
+        tool,
+        &files.iter().map(|(f, _)| *f).collect::<Vec<_>>(),
+        manifest.get_source_dir(),
+    ));
+    Ok(output)
+}
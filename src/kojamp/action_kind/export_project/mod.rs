@@ -0,0 +1,5 @@
+mod content;
+mod main;
+mod reports;
+
+pub use main::main as export_project;
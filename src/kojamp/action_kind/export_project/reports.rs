@@ -0,0 +1,106 @@
+use crate::core::{
+    models::ManifestError,
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const INVALID_DEPENDENCY_ENTRY: &str = "Invalid dependency entry";
+const UNTRANSLATED_DEPENDENCY: &str = "Dependency not exported";
+const DEPENDENCY_LEFT_OUT: &str = "Dependency left out of the build";
+const PROJECT_DEPENDENCY: &str = "`$$$` is a kojamp project dependency";
+const BUILD_FILES_ALREADY_EXIST: &str = "Build files already exist";
+const COULD_NOT_WRITE_FILE: &str = "Couldn't write `$$$`";
+const PROJECT_EXPORTED: &str = "Project exported to $$$";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => KojampReport::new(
+            ReportType::Error,
+            PROBABLY_A_NON_KOJAMP_PROJECT,
+            messages::kojamp_toml_not_found(),
+        ),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn invalid_dependency_entry(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        INVALID_DEPENDENCY_ENTRY,
+        messages::invalid_dependency_entry(name),
+    )
+}
+
+pub fn untranslated_dependency(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        UNTRANSLATED_DEPENDENCY,
+        messages::dependency_not_exported(name),
+    )
+}
+
+pub fn project_dependency_left_out(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        DEPENDENCY_LEFT_OUT,
+        messages::project_dependency_left_out_of_gradle(name),
+    )
+}
+
+pub fn project_dependency_note(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        PROJECT_DEPENDENCY.replace("$$$", &format!("{}", name.bright_yellow())),
+        messages::exported_project_dependency(),
+    )
+}
+
+pub fn build_files_already_exist(files: &[&str]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        BUILD_FILES_ALREADY_EXIST,
+        messages::build_files_already_exist(files),
+    )
+}
+
+pub fn could_not_write_file(file: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_WRITE_FILE.replace("$$$", file),
+        messages::could_not_create_dir_file(file),
+    )
+}
+
+pub fn success_report(
+    tool: &str,
+    files: &[&str],
+    source_dir: &str,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        PROJECT_EXPORTED.replace("$$$", &format!("{}", tool.bright_green())),
+        messages::project_exported(tool, files, source_dir),
+    )
+}
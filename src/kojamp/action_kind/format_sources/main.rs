@@ -0,0 +1,117 @@
+use super::{reports::*, utils::*};
+use crate::{
+    core::{
+        models::{ProjectManifest, ProjectWorkspace},
+        reporting::KojampReport,
+    },
+    utils::{path::display, source},
+};
+use clap::ArgMatches;
+use std::path::Path;
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let package = matching.get_one::<String>("package");
+    let check = matching.get_flag("check");
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+
+    let (_, graph, targets) =
+        ProjectWorkspace::targets(&curdir, package.map(String::as_str))
+            .map_err(|e| vec![target_error(e)])?;
+
+    let mut output: Vec<KojampReport> = Vec::new();
+    let mut unformatted = 0;
+    let mut files = 0;
+    for index in targets {
+        let node = graph.get_node(index);
+        let (count, reports) = format_project(
+            node.get_root(),
+            node.get_manifest(),
+            &curdir,
+            check,
+        )
+        .map_err(|e| vec![e])?;
+        files += count;
+        unformatted += reports.len();
+        output.extend(reports);
+    }
+
+    match (check, unformatted) {
+        (true, 0) => Ok(vec![sources_formatted_already(files)]),
+        (true, n) => {
+            output.push(unformatted_sources(n));
+            Err(output)
+        }
+        _ => Ok(vec![sources_formatted(files)]),
+    }
+}
+
+// formats a single project (returning the file count and the unformatted
+// files when checking)
+fn format_project(
+    root: &Path,
+    manifest: &ProjectManifest,
+    base: &Path,
+    check: bool,
+) -> Result<(usize, Vec<KojampReport>), KojampReport> {
+    let kind = manifest.get_kind();
+    let source_dir = manifest.get_source_dir();
+    let formatter = find_formatter(manifest.get_formatter(), kind)
+        .ok_or(unknown_formatter(manifest.get_formatter(), kind))?;
+
+    if !root.join(source_dir).is_dir() {
+        return Err(src_dir_is_missing(source_dir));
+    }
+
+    let sources: Vec<String> =
+        source::collect_sources(kind, &root.join(source_dir))
+            .map_err(|p| unreadable_src_content(source_dir, &p))?
+            .into_iter()
+            .map(|f| f.to_string_lossy().to_string())
+            .collect();
+    if sources.is_empty() {
+        return Ok((0, Vec::new()));
+    }
+
+    let classpath = formatter
+        .classpath(root, manifest.get_formatter_jar())
+        .map_err(|p| formatter_not_found(formatter.name, &p))?;
+
+    let result = formatter
+        .run(&classpath, &sources, check)
+        .ok_or(formatter_failed(formatter.name))?;
+    let listed = String::from_utf8_lossy(&result.stdout).to_string();
+
+    // the listed files (or the ktlint findings) start with their path, which
+    // ktlint prints relative to the current dir
+    let findings: Vec<(&String, &str)> = listed
+        .lines()
+        .map(str::trim_start)
+        .filter_map(|l| {
+            let file = sources.iter().find(|s| {
+                [s.to_string(), display(Path::new(s), base)]
+                    .iter()
+                    .any(|p| l == p || l.starts_with(&format!("{}:", p)))
+            })?;
+            Some((file, l))
+        })
+        .collect();
+
+    // ktlint also finds lint problems that formatting can't fix
+    let unformatted: Vec<KojampReport> = sources
+        .iter()
+        .filter(|s| {
+            findings
+                .iter()
+                .any(|(f, l)| f == s && !l.contains(KTLINT_LINT_ONLY))
+        })
+        .map(|s| unformatted_file(&display(Path::new(s), base), formatter.name))
+        .collect();
+
+    match (result.status.success(), findings.is_empty()) {
+        (false, true) => Err(formatter_failed(formatter.name)),
+        _ => Ok((sources.len(), unformatted)),
+    }
+}
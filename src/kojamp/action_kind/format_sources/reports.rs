@@ -0,0 +1,173 @@
+use crate::core::{
+    models::{GraphError, ManifestError, TargetError},
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+use std::path::Path;
+
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const NOT_A_WORKSPACE: &str = "Not a workspace";
+const UNKNOWN_MEMBER: &str = "Unknown workspace member";
+const INVALID_PROJECT_DEPENDENCY: &str = "Invalid project at `$$$`";
+const DEPENDENCY_CYCLE: &str = "Dependency cycle";
+const COULD_NOT_FIND_SRC_DIR: &str = "Couldn't find `$$$` dir";
+const UNREADABLE_SRC_CONTENT: &str = "Unreadable src content";
+const UNKNOWN_FORMATTER: &str = "Unknown formatter";
+const FORMATTER_NOT_FOUND: &str = "Couldn't find the `$$$` jar";
+const FORMATTER_FAILED: &str = "The `$$$` formatter failed";
+const UNFORMATTED_FILE: &str = "`$$$` isn't formatted";
+const UNFORMATTED_SOURCES: &str = "Unformatted sources";
+const SOURCES_FORMATTED: &str = "Sources formatted";
+const SOURCES_FORMATTED_ALREADY: &str = "Sources already formatted";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => KojampReport::new(
+            ReportType::Error,
+            PROBABLY_A_NON_KOJAMP_PROJECT,
+            messages::kojamp_toml_not_found(),
+        ),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn not_a_workspace() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NOT_A_WORKSPACE,
+        messages::not_a_workspace(),
+    )
+}
+
+pub fn unknown_member(name: &str, members: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNKNOWN_MEMBER,
+        messages::unknown_workspace_member(name, members),
+    )
+}
+
+pub fn target_error(error: TargetError) -> KojampReport {
+    match error {
+        TargetError::Manifest(e) => manifest_error(e),
+        TargetError::NotAWorkspace => not_a_workspace(),
+        TargetError::Graph(e) => graph_error(e),
+        TargetError::UnknownMember(name, members) => {
+            unknown_member(&name, &members)
+        }
+    }
+}
+
+pub fn graph_error(error: GraphError) -> KojampReport {
+    match error {
+        GraphError::Manifest(path, e) => KojampReport::new(
+            ReportType::Error,
+            INVALID_PROJECT_DEPENDENCY.replace("$$$", &path.to_string_lossy()),
+            match e {
+                ManifestError::Missing => messages::kojamp_toml_not_found(),
+                ManifestError::Unreadable => {
+                    messages::toml_file_could_not_be_read()
+                }
+                ManifestError::Invalid => messages::invalid_toml_file(),
+            },
+        ),
+        GraphError::Cycle(cycle) => KojampReport::new(
+            ReportType::Error,
+            DEPENDENCY_CYCLE,
+            messages::dependency_cycle(&cycle),
+        ),
+    }
+}
+
+pub fn src_dir_is_missing(source_dir: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_FIND_SRC_DIR.replace("$$$", source_dir),
+        messages::empty_message(),
+    )
+}
+
+pub fn unreadable_src_content(source_dir: &str, path: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNREADABLE_SRC_CONTENT,
+        messages::unreadable_src_content(source_dir, path),
+    )
+}
+
+pub fn unknown_formatter(name: &str, kind: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNKNOWN_FORMATTER,
+        messages::unknown_formatter(name, kind),
+    )
+}
+
+pub fn formatter_not_found(name: &str, searched: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        FORMATTER_NOT_FOUND.replace("$$$", &format!("{}", name.bright_red())),
+        messages::formatter_not_found(searched),
+    )
+}
+
+pub fn formatter_failed(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        FORMATTER_FAILED.replace("$$$", &format!("{}", name.bright_red())),
+        messages::empty_message(),
+    )
+}
+
+pub fn unformatted_file(file: &str, formatter: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNFORMATTED_FILE.replace("$$$", file),
+        messages::unformatted_file(formatter),
+    )
+}
+
+pub fn unformatted_sources(count: usize) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNFORMATTED_SOURCES,
+        messages::unformatted_sources(count),
+    )
+}
+
+pub fn sources_formatted(count: usize) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        SOURCES_FORMATTED,
+        messages::sources_formatted(count),
+    )
+}
+
+pub fn sources_formatted_already(count: usize) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        SOURCES_FORMATTED_ALREADY,
+        messages::sources_checked_for_format(count),
+    )
+}
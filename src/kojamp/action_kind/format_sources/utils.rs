@@ -0,0 +1,142 @@
+use crate::core::{
+    consts::files_and_dirs::JAR_FILE_EXTENSION,
+    models::{DependencyTree, ProjectDependency},
+};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+pub struct Formatter {
+    pub name: &'static str,
+    pub kind: &'static str,
+    group: &'static str,
+    artifact: &'static str,
+    main_class: &'static str,
+}
+
+pub const FORMATTERS: [Formatter; 3] = [
+    Formatter {
+        name: "google-java-format",
+        kind: "java",
+        group: "com.google.googlejavaformat",
+        artifact: "google-java-format",
+        main_class: "com.google.googlejavaformat.java.Main",
+    },
+    Formatter {
+        name: "ktlint",
+        kind: "kotlin",
+        group: "com.pinterest.ktlint",
+        artifact: "ktlint-cli",
+        main_class: "com.pinterest.ktlint.Main",
+    },
+    Formatter {
+        name: "ktfmt",
+        kind: "kotlin",
+        group: "com.facebook",
+        artifact: "ktfmt",
+        main_class: "com.facebook.ktfmt.cli.Main",
+    },
+];
+
+// classifiers of the jars that already hold their dependencies
+const FAT_JAR_CLASSIFIERS: [&str; 4] = [
+    "all-deps",
+    "jar-with-dependencies",
+    "with-dependencies",
+    "all",
+];
+
+// the suffix of the ktlint findings that formatting can't fix
+pub const KTLINT_LINT_ONLY: &str = "(cannot be auto-corrected)";
+
+// google-java-format reaches javac internals
+const JAVAC_EXPORTS: [&str; 6] =
+    ["api", "code", "file", "parser", "tree", "util"];
+
+impl Formatter {
+    // the configured jar, a fat jar from the maven repository or the plain
+    // one plus its own dependencies (`Err` holds the searched location)
+    pub fn classpath(
+        &self,
+        root: &Path,
+        configured: Option<&str>,
+    ) -> Result<Vec<PathBuf>, PathBuf> {
+        if let Some(jar) = configured {
+            let jar = root.join(jar);
+            return match jar.is_file() {
+                true => Ok(vec![jar]),
+                false => Err(jar),
+            };
+        }
+
+        let (version_dir, version) =
+            ProjectDependency::latest_installed(self.group, self.artifact)?;
+
+        for classifier in FAT_JAR_CLASSIFIERS {
+            let jar = version_dir.join(format!(
+                "{}-{}-{}.{}",
+                self.artifact, version, classifier, JAR_FILE_EXTENSION
+            ));
+            if jar.is_file() {
+                return Ok(vec![jar]);
+            }
+        }
+
+        let tree = DependencyTree::resolve_artifact(
+            self.group,
+            self.artifact,
+            &version,
+        );
+        match tree.missing().is_empty() {
+            true => Ok(tree.jars()),
+            false => Err(version_dir),
+        }
+    }
+
+    // `check` only lists the files (the stdout is captured, as the ktlint
+    // findings that formatting leaves behind)
+    pub fn run(
+        &self,
+        classpath: &[PathBuf],
+        files: &[String],
+        check: bool,
+    ) -> Option<Output> {
+        let mut command = Command::new("java");
+        if self.name == "google-java-format" {
+            for package in JAVAC_EXPORTS {
+                command.arg(format!(
+                    "--add-exports=jdk.compiler/com.sun.tools.javac.{}=ALL-UNNAMED",
+                    package
+                ));
+            }
+        }
+        command
+            .arg("-cp")
+            .arg(env::join_paths(classpath).ok()?)
+            .arg(self.main_class);
+
+        match (self.name, check) {
+            ("google-java-format", false) => command.arg("--replace"),
+            ("ktlint", false) => command.arg("--format"),
+            ("ktlint", true) => &mut command,
+            (_, true) => command.arg("--dry-run"),
+            _ => &mut command,
+        };
+        command.args(files);
+
+        match check || self.name == "ktlint" {
+            true => command.stdout(Stdio::piped()).output().ok(),
+            false => command.status().ok().map(|status| Output {
+                status,
+                stdout: Vec::new(),
+                stderr: Vec::new(),
+            }),
+        }
+    }
+}
+
+pub fn find_formatter(name: &str, kind: &str) -> Option<&'static Formatter> {
+    FORMATTERS.iter().find(|f| f.name == name && f.kind == kind)
+}
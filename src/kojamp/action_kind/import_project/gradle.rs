@@ -0,0 +1,309 @@
+use super::utils::{dependency_path, normalize_jvm_target, ImportedProject};
+use crate::core::models::ProjectDependency;
+use regex::Regex;
+use std::collections::HashMap;
+
+// only a simple subset of the gradle dsl (both groovy and kotlin flavors)
+// is understood, anything else is reported as untranslated
+const KNOWN_PLUGINS: [&str; 5] = [
+    "java",
+    "application",
+    "org.jetbrains.kotlin.jvm",
+    "kotlin",
+    "jvm",
+];
+const COMPILE_CONFIGURATIONS: [&str; 5] = [
+    "implementation",
+    "api",
+    "compileOnly",
+    "runtimeOnly",
+    "compile",
+];
+
+fn capture(pattern: &str, content: &str) -> Option<String> {
+    Regex::new(pattern)
+        .unwrap()
+        .captures(content)
+        .map(|c| c[1].trim().to_string())
+}
+
+// plain string assignments (`val x = "..."`, `def x = '...'`, `ext.x = ...`
+// or the ones at `ext { }` blocks) that versions can refer to
+fn properties(content: &str) -> HashMap<String, String> {
+    let assignment = Regex::new(
+        r#"(?m)^\s*(?:(?:val|var|def)\s+|ext\.)?(\w+)\s*=\s*["']([^"'$]*)["']"#,
+    )
+    .unwrap();
+    assignment
+        .captures_iter(content)
+        .map(|c| (c[1].to_string(), c[2].trim().to_string()))
+        .collect()
+}
+
+// `$x` and `${x}` references are replaced by the known properties (`None`
+// when some of them can't be resolved)
+fn resolve(
+    value: &str,
+    properties: &HashMap<String, String>,
+) -> Option<String> {
+    let reference = Regex::new(r"\$(?:\{([^}]+)\}|(\w+))").unwrap();
+    let mut missing = false;
+    let output = reference.replace_all(value, |c: &regex::Captures| {
+        let name = c.get(1).or(c.get(2)).unwrap().as_str().trim();
+        match properties.get(name) {
+            Some(v) => v.clone(),
+            None => {
+                missing = true;
+                c[0].to_string()
+            }
+        }
+    });
+    (!missing).then(|| output.to_string())
+}
+
+fn dependency(
+    output: &mut ImportedProject,
+    configuration: &str,
+    dep: ProjectDependency,
+) {
+    let description = match &dep {
+        ProjectDependency::Maven {
+            group, artifact, ..
+        } => format!("{}:{}", group, artifact),
+        ProjectDependency::Path(p) => p.to_string_lossy().to_string(),
+    };
+
+    if COMPILE_CONFIGURATIONS.contains(&configuration) {
+        output.add_dependency(dep);
+    } else {
+        output.untranslated(format!(
+            "`{}` dependency `{}`",
+            configuration, description
+        ));
+    }
+}
+
+fn dependencies(
+    content: &str,
+    properties: &HashMap<String, String>,
+    output: &mut ImportedProject,
+) {
+    let coordinate = Regex::new(
+        r#"(?m)^\s*(\w+)\s*\(?\s*["']([^"':\s]+):([^"':\s]+):([^"':@\s]+)["']"#,
+    )
+    .unwrap();
+    let map_notation = Regex::new(
+        r#"(?m)^\s*(\w+)\s*\(?\s*group\s*[:=]\s*["']([^"']+)["']\s*,\s*name\s*[:=]\s*["']([^"']+)["']\s*,\s*version\s*[:=]\s*["']([^"']+)["']"#,
+    )
+    .unwrap();
+    let files =
+        Regex::new(r#"(?m)^\s*(\w+)\s*\(?\s*files\(\s*["']([^"']+)["']"#)
+            .unwrap();
+    let others = Regex::new(r#"(?m)^\s*(\w+)\s*\(?\s*(project|platform|enforcedPlatform|fileTree)\s*\(\s*([^)]*)\)"#).unwrap();
+
+    for c in coordinate
+        .captures_iter(content)
+        .chain(map_notation.captures_iter(content))
+    {
+        let version = match resolve(&c[4], properties) {
+            Some(v) => v,
+            None => {
+                output.untranslated(format!(
+                    "dependency `{}:{}` (unknown version)",
+                    &c[2], &c[3]
+                ));
+                continue;
+            }
+        };
+        let dep = ProjectDependency::Maven {
+            group: c[2].to_string(),
+            artifact: c[3].to_string(),
+            version,
+        };
+        dependency(output, &c[1], dep);
+    }
+
+    for c in files.captures_iter(content) {
+        match dependency_path(&c[2]) {
+            Some(dep) => dependency(output, &c[1], dep),
+            None => output.untranslated(format!("files `{}`", &c[2])),
+        }
+    }
+
+    for c in others.captures_iter(content) {
+        output.untranslated(format!("{}({}) dependency", &c[2], c[3].trim()));
+    }
+}
+
+fn plugins(content: &str, output: &mut ImportedProject) {
+    let id = Regex::new(r#"(?m)^\s*id\s*\(?\s*["']([^"']+)["']"#).unwrap();
+    let kotlin =
+        Regex::new(r#"(?m)^\s*kotlin\s*\(\s*["']([^"']+)["']"#).unwrap();
+    let apply =
+        Regex::new(r#"(?m)^\s*apply\s+plugin\s*:\s*["']([^"']+)["']"#).unwrap();
+    let bare =
+        Regex::new(r"(?m)^\s*(java|application|`java-library`)\s*$").unwrap();
+
+    let ids = id
+        .captures_iter(content)
+        .chain(apply.captures_iter(content))
+        .chain(bare.captures_iter(content))
+        .map(|c| c[1].to_string())
+        .chain(
+            kotlin
+                .captures_iter(content)
+                .map(|c| format!("kotlin-{}", &c[1])),
+        );
+
+    for plugin in ids {
+        match plugin.as_str() {
+            "org.jetbrains.kotlin.jvm" | "kotlin" | "kotlin-jvm" => {
+                output.kind = Some(String::from("kotlin"))
+            }
+            p if KNOWN_PLUGINS.contains(&p) => {
+                output.kind.get_or_insert(String::from("java"));
+            }
+            p => output.untranslated(format!("plugin `{}`", p)),
+        }
+    }
+}
+
+pub fn parse(content: &str, settings: Option<&str>) -> ImportedProject {
+    let properties = properties(content);
+    let mut output = ImportedProject {
+        name: settings.and_then(|s| {
+            capture(r#"rootProject\.name\s*=\s*["']([^"']+)["']"#, s)
+        }),
+        group: capture(r#"(?m)^\s*group\s*=\s*["']([^"']+)["']"#, content),
+        version: capture(r#"(?m)^\s*version\s*=\s*["']([^"']+)["']"#, content)
+            .and_then(|v| resolve(&v, &properties)),
+        main_class: capture(
+            r#"mainClass(?:Name)?\s*(?:\.set\s*\(|=)\s*["']([^"']+)["']"#,
+            content,
+        )
+        .and_then(|m| resolve(&m, &properties)),
+        ..Default::default()
+    };
+
+    plugins(content, &mut output);
+    dependencies(content, &properties, &mut output);
+
+    output.jvm_target = [
+        r"jvmToolchain\s*\(\s*(\d+)\s*\)",
+        r"JavaLanguageVersion\.of\s*\(\s*(\d+)\s*\)",
+        r"release\.set\s*\(\s*(\d+)\s*\)",
+        r"JavaVersion\.VERSION_(\d+(?:_\d+)?)",
+        r"JvmTarget\.JVM_(\d+(?:_\d+)?)",
+        r#"jvmTarget\s*=\s*["']([\d.]+)["']"#,
+        r#"(?:sourceCompatibility|targetCompatibility)\s*=\s*["']?([\d.]+)"#,
+    ]
+    .iter()
+    .find_map(|p| capture(p, content))
+    .map(|t| normalize_jvm_target(&t.replace('_', ".")));
+
+    // `main` source set dirs (`test` ones are just skipped)
+    let source_dir = Regex::new(r#"(?m)^(.*?)srcDirs?\s*(?:\(|=)\s*(?:listOf\s*\(|\[)?\s*["']([^"']+)["']"#).unwrap();
+    output.source_dirs = source_dir
+        .captures_iter(content)
+        .filter(|c| !c[1].contains("test"))
+        .map(|c| c[2].trim_start_matches("./").to_string())
+        .collect();
+
+    if settings
+        .is_some_and(|s| Regex::new(r"(?m)^\s*include\b").unwrap().is_match(s))
+    {
+        output.untranslated("included subprojects (multi-project build)");
+    }
+    for (pattern, description) in [
+        (
+            r"(?m)^\s*(subprojects|allprojects)\s*\{",
+            "subprojects/allprojects blocks",
+        ),
+        (r"(?m)^\s*maven\s*[({]", "custom repositories"),
+        (r"(?m)^\s*(processResources|resources)\b", "resources"),
+    ] {
+        if Regex::new(pattern).unwrap().is_match(content) {
+            output.untranslated(description);
+        }
+    }
+
+    // compile task tweaks were already read as the jvm target
+    let custom_task = Regex::new(r"^\s*tasks?[.\s(]").unwrap();
+    if content
+        .lines()
+        .filter(|l| !l.contains("JavaCompile") && !l.contains("KotlinCompile"))
+        .any(|l| custom_task.is_match(l))
+    {
+        output.untranslated("custom tasks");
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coordinates(output: &ImportedProject) -> Vec<String> {
+        output
+            .dependencies
+            .iter()
+            .filter_map(|(_, d)| match d {
+                ProjectDependency::Maven {
+                    group,
+                    artifact,
+                    version,
+                } => Some(format!("{}:{}:{}", group, artifact, version)),
+                ProjectDependency::Path(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn kotlin_dsl_properties_are_resolved() {
+        let content = r#"
+            val gsonVersion = "2.10.1"
+            val appVersion = "1.2.0"
+
+            version = "$appVersion"
+
+            dependencies {
+                implementation("com.google.code.gson:gson:$gsonVersion")
+                implementation("org.slf4j:slf4j-api:${slf4jVersion}")
+            }
+        "#;
+        let output = parse(content, None);
+
+        assert_eq!(output.version.as_deref(), Some("1.2.0"));
+        assert_eq!(coordinates(&output), ["com.google.code.gson:gson:2.10.1"]);
+        assert_eq!(
+            output.untranslated,
+            ["dependency `org.slf4j:slf4j-api` (unknown version)"]
+        );
+    }
+
+    #[test]
+    fn groovy_ext_properties_are_resolved() {
+        let content = r#"
+            ext {
+                junitVersion = '5.10.0'
+            }
+            ext.gsonVersion = '2.10.1'
+
+            dependencies {
+                implementation "com.google.code.gson:gson:${gsonVersion}"
+                implementation group: 'org.junit', name: 'junit-bom', version: "$junitVersion"
+            }
+        "#;
+        let output = parse(content, None);
+
+        assert_eq!(
+            coordinates(&output),
+            [
+                "com.google.code.gson:gson:2.10.1",
+                "org.junit:junit-bom:5.10.0",
+            ]
+        );
+        assert!(output.untranslated.is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use super::{gradle, maven, reports::*, utils::*};
+use crate::core::{
+    consts::files_and_dirs::{PROGRAM_TOML_FILE_NAME, TOML_FILE_EXTENSION},
+    contracts::IsValid,
+    models::ProjectName,
+    reporting::KojampReport,
+};
+use clap::ArgMatches;
+use std::fs;
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let force = matching.get_flag("force");
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+
+    let mut toml_file = curdir.join(PROGRAM_TOML_FILE_NAME);
+    toml_file.set_extension(TOML_FILE_EXTENSION);
+
+    if toml_file.exists() && !force {
+        return Err(vec![kojamp_toml_already_exists()]);
+    }
+
+    // pom.xml comes first, then the kotlin and groovy gradle files
+    let build_file = [MAVEN_BUILD_FILE]
+        .into_iter()
+        .chain(GRADLE_BUILD_FILES)
+        .find(|f| curdir.join(f).is_file())
+        .ok_or(vec![no_build_file_found()])?;
+
+    let content = fs::read_to_string(curdir.join(build_file))
+        .map_err(|_| vec![could_not_read_file(build_file)])?;
+
+    let mut project = match build_file {
+        MAVEN_BUILD_FILE => maven::parse(&content)
+            .map_err(|_| vec![invalid_build_file(build_file)])?,
+        _ => {
+            let settings = GRADLE_SETTINGS_FILES
+                .iter()
+                .find_map(|f| fs::read_to_string(curdir.join(f)).ok());
+            gradle::parse(&content, settings.as_deref())
+        }
+    };
+
+    let dir_name = curdir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = project_name(project.name.as_deref().unwrap_or(&dir_name));
+    if !ProjectName::from(name.as_str()).is_valid() {
+        return Err(vec![invalid_project_name(&name)]);
+    }
+
+    let kind = match project.kind.clone() {
+        Some(k) => k,
+        None if count_sources(&curdir, "kt")
+            > count_sources(&curdir, "java") =>
+        {
+            String::from("kotlin")
+        }
+        None => String::from("java"),
+    };
+
+    let mut output: Vec<KojampReport> = Vec::new();
+
+    // the kojamp build reads a single source dir
+    let declared = project.source_dirs.clone();
+    let source_dir = match declared.first() {
+        Some(d) => {
+            if declared.len() > 1 {
+                project.untranslated(format!(
+                    "extra source dirs ({})",
+                    declared[1..].join(", ")
+                ));
+            }
+            d.clone()
+        }
+        None => default_source_dirs(&kind)
+            .into_iter()
+            .find(|d| curdir.join(d).is_dir())
+            .unwrap_or(default_source_dirs(&kind)[0])
+            .to_string(),
+    };
+
+    if !curdir.join(&source_dir).is_dir() {
+        output.push(source_dir_is_missing(&source_dir));
+    }
+    if project.main_class.is_none() {
+        output.push(main_class_not_found(&name, &kind));
+    }
+    output.extend(project.untranslated.iter().map(|u| untranslated_setting(u)));
+
+    let resources_dir =
+        Some(DEFAULT_RESOURCES_DIR).filter(|d| curdir.join(d).is_dir());
+    fs::write(
+        &toml_file,
+        kojamp_toml(&project, &name, &kind, &source_dir, resources_dir),
+    )
+    .map_err(|_| vec![could_not_write_kojamp_toml()])?;
+
+    output.push(success_report(
+        &name,
+        build_file,
+        project.dependencies.len(),
+        &source_dir,
+    ));
+    Ok(output)
+}
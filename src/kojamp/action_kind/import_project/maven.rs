@@ -0,0 +1,426 @@
+use super::utils::{dependency_path, normalize_jvm_target, ImportedProject};
+use crate::core::models::{ProjectAuthor, ProjectDependency};
+use regex::Regex;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+
+// plugins whose settings are already covered by the `Kojamp.toml` fields
+const KNOWN_PLUGINS: [&str; 10] = [
+    "maven-compiler-plugin",
+    "maven-jar-plugin",
+    "maven-resources-plugin",
+    "maven-surefire-plugin",
+    "maven-install-plugin",
+    "maven-deploy-plugin",
+    "maven-clean-plugin",
+    "maven-site-plugin",
+    "exec-maven-plugin",
+    "kotlin-maven-plugin",
+];
+
+fn child<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &str,
+) -> Option<Node<'a, 'input>> {
+    node.children().find(|c| c.has_tag_name(name))
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &str,
+) -> Vec<Node<'a, 'input>> {
+    node.children().filter(|c| c.has_tag_name(name)).collect()
+}
+
+fn text(node: Node, name: &str) -> Option<String> {
+    child(node, name)
+        .and_then(|c| c.text())
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+// `${...}` references are replaced by the pom properties (`None` when some
+// of them can't be resolved)
+fn resolve(
+    value: &str,
+    properties: &HashMap<String, String>,
+) -> Option<String> {
+    let reference = Regex::new(r"\$\{([^}]+)\}").unwrap();
+    let mut output = value.to_string();
+
+    for _ in 0..8 {
+        if !reference.is_match(&output) {
+            return Some(output);
+        }
+        let mut missing = false;
+        output = reference
+            .replace_all(&output, |c: &regex::Captures| {
+                match properties.get(&c[1]) {
+                    Some(v) => v.clone(),
+                    None => {
+                        missing = true;
+                        c[0].to_string()
+                    }
+                }
+            })
+            .to_string();
+        if missing {
+            return None;
+        }
+    }
+
+    None
+}
+
+fn properties(project: Node) -> HashMap<String, String> {
+    let mut output = HashMap::new();
+    let parent = child(project, "parent");
+
+    // paths are kept relative to the project root
+    output.insert(String::from("project.basedir"), String::from("."));
+    output.insert(String::from("basedir"), String::from("."));
+
+    for (key, field) in [
+        ("project.groupId", "groupId"),
+        ("project.artifactId", "artifactId"),
+        ("project.version", "version"),
+    ] {
+        let value = text(project, field)
+            .or_else(|| parent.and_then(|p| text(p, field)));
+        if let Some(v) = value {
+            output.insert(key.to_string(), v.clone());
+            output.insert(key.replace("project.", "pom."), v);
+        }
+    }
+
+    if let Some(props) = child(project, "properties") {
+        for prop in props.children().filter(|c| c.is_element()) {
+            let value = prop.text().unwrap_or_default().trim().to_string();
+            output.insert(prop.tag_name().name().to_string(), value);
+        }
+    }
+
+    output
+}
+
+fn managed_version(
+    project: Node,
+    group: &str,
+    artifact: &str,
+) -> Option<String> {
+    let managed = child(project, "dependencyManagement")
+        .and_then(|d| child(d, "dependencies"))?;
+    children(managed, "dependency")
+        .into_iter()
+        .find(|d| {
+            text(*d, "groupId").as_deref() == Some(group)
+                && text(*d, "artifactId").as_deref() == Some(artifact)
+        })
+        .and_then(|d| text(d, "version"))
+}
+
+fn plugin_configuration(
+    plugins: &[Node],
+    artifact: &str,
+    field: &str,
+) -> Option<String> {
+    plugins
+        .iter()
+        .filter(|p| text(**p, "artifactId").as_deref() == Some(artifact))
+        .find_map(|p| child(*p, "configuration").and_then(|c| text(c, field)))
+}
+
+fn dependencies(
+    project: Node,
+    properties: &HashMap<String, String>,
+    output: &mut ImportedProject,
+) {
+    let deps = match child(project, "dependencies") {
+        Some(d) => children(d, "dependency"),
+        None => return,
+    };
+
+    for dep in deps {
+        let field = |name: &str| {
+            let value = text(dep, name).unwrap_or_default();
+            resolve(&value, properties).unwrap_or(value)
+        };
+        let group = field("groupId");
+        let artifact = field("artifactId");
+        let coordinate = format!("{}:{}", group, artifact);
+        let scope = text(dep, "scope").unwrap_or(String::from("compile"));
+
+        // kotlinc already bundles the kotlin runtime
+        if group == "org.jetbrains.kotlin"
+            && artifact.starts_with("kotlin-stdlib")
+        {
+            output.kind = Some(String::from("kotlin"));
+            continue;
+        }
+
+        match scope.as_str() {
+            "test" => {
+                output
+                    .untranslated(format!("test dependency `{}`", coordinate));
+                continue;
+            }
+            "system" => {
+                let path = text(dep, "systemPath")
+                    .and_then(|p| resolve(&p, properties));
+                match path.as_deref().and_then(dependency_path) {
+                    Some(d) => output.add_dependency(d),
+                    None => output.untranslated(format!(
+                        "system dependency `{}`",
+                        coordinate
+                    )),
+                }
+                continue;
+            }
+            "import" => {
+                output.untranslated(format!("imported bom `{}`", coordinate));
+                continue;
+            }
+            _ => {}
+        }
+
+        if text(dep, "classifier").is_some()
+            || text(dep, "type").is_some_and(|t| t != "jar")
+        {
+            output.untranslated(format!("classifier/type of `{}`", coordinate));
+        }
+
+        let version = text(dep, "version")
+            .or_else(|| managed_version(project, &group, &artifact))
+            .and_then(|v| resolve(&v, properties));
+
+        match version {
+            Some(v) => output.add_dependency(ProjectDependency::Maven {
+                group,
+                artifact,
+                version: v,
+            }),
+            None => output.untranslated(format!(
+                "dependency `{}` (unknown version)",
+                coordinate
+            )),
+        }
+    }
+}
+
+pub fn parse(content: &str) -> Result<ImportedProject, ()> {
+    let document = Document::parse(content).map_err(|_| ())?;
+    let project = document.root_element();
+    if !project.has_tag_name("project") {
+        return Err(());
+    }
+
+    let properties = properties(project);
+    let mut output = ImportedProject {
+        name: text(project, "artifactId"),
+        group: properties.get("project.groupId").cloned(),
+        version: properties
+            .get("project.version")
+            .and_then(|v| resolve(v, &properties)),
+        ..Default::default()
+    };
+
+    if child(project, "parent").is_some() {
+        output.untranslated("parent pom (inherited settings)");
+    }
+    if child(project, "modules").is_some() {
+        output.untranslated("modules (multi-module build)");
+    }
+    if text(project, "packaging").is_some_and(|p| p != "jar") {
+        output.untranslated("packaging (only jar is supported)");
+    }
+    for field in ["repositories", "profiles"] {
+        if child(project, field).is_some() {
+            output.untranslated(field);
+        }
+    }
+
+    if let Some(devs) = child(project, "developers") {
+        output.authors = children(devs, "developer")
+            .into_iter()
+            .filter_map(|d| {
+                Some(ProjectAuthor::new(text(d, "name")?, text(d, "email")))
+            })
+            .collect();
+    }
+
+    dependencies(project, &properties, &mut output);
+
+    let build = child(project, "build");
+    let plugins: Vec<Node> = build
+        .and_then(|b| child(b, "plugins"))
+        .map(|p| children(p, "plugin"))
+        .unwrap_or_default();
+
+    for plugin in plugins.iter() {
+        match text(*plugin, "artifactId") {
+            Some(a) if a == "kotlin-maven-plugin" => {
+                output.kind = Some(String::from("kotlin"))
+            }
+            Some(a) if KNOWN_PLUGINS.contains(&a.as_str()) => {}
+            Some(a) => output.untranslated(format!("plugin `{}`", a)),
+            None => {}
+        }
+    }
+
+    if let Some(b) = build {
+        if child(b, "resources").is_some() {
+            output.untranslated("resources");
+        }
+        let source_dir =
+            text(b, "sourceDirectory").and_then(|s| resolve(&s, &properties));
+        output.source_dirs.extend(source_dir);
+    }
+
+    let kotlin_dirs = plugins
+        .iter()
+        .filter(|p| {
+            text(**p, "artifactId").as_deref() == Some("kotlin-maven-plugin")
+        })
+        .flat_map(|p| p.descendants().filter(|d| d.has_tag_name("sourceDir")))
+        .filter_map(|d| d.text().and_then(|t| resolve(t.trim(), &properties)));
+    output.source_dirs.extend(kotlin_dirs);
+
+    output.source_dirs = output
+        .source_dirs
+        .iter()
+        .map(|d| d.trim_start_matches("./").to_string())
+        .collect();
+
+    output.jvm_target = [
+        "maven.compiler.release",
+        "maven.compiler.target",
+        "maven.compiler.source",
+        "kotlin.compiler.jvmTarget",
+    ]
+    .iter()
+    .find_map(|p| properties.get(*p).and_then(|v| resolve(v, &properties)))
+    .or_else(|| {
+        ["release", "target", "source"]
+            .iter()
+            .find_map(|f| {
+                plugin_configuration(&plugins, "maven-compiler-plugin", f)
+            })
+            .or_else(|| {
+                plugin_configuration(
+                    &plugins,
+                    "kotlin-maven-plugin",
+                    "jvmTarget",
+                )
+            })
+            .and_then(|v| resolve(&v, &properties))
+    })
+    .map(|t| normalize_jvm_target(&t));
+
+    // `<mainClass>` (jar, exec, shade, ...), `<Main-Class>` or the usual
+    // properties
+    output.main_class = plugins
+        .iter()
+        .flat_map(|p| p.descendants())
+        .find(|d| d.has_tag_name("mainClass") || d.has_tag_name("Main-Class"))
+        .and_then(|d| d.text())
+        .map(|t| t.trim().to_string())
+        .or_else(|| {
+            ["exec.mainClass", "main.class", "mainClass"]
+                .iter()
+                .find_map(|p| properties.get(*p).cloned())
+        })
+        .and_then(|m| resolve(&m, &properties))
+        .filter(|m| !m.is_empty());
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coordinates(output: &ImportedProject) -> Vec<String> {
+        output
+            .dependencies
+            .iter()
+            .filter_map(|(_, d)| match d {
+                ProjectDependency::Maven {
+                    group,
+                    artifact,
+                    version,
+                } => Some(format!("{}:{}:{}", group, artifact, version)),
+                ProjectDependency::Path(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn properties_are_resolved() {
+        let content = r#"
+            <project>
+                <groupId>com.foo</groupId>
+                <artifactId>app</artifactId>
+                <version>${revision}</version>
+                <properties>
+                    <revision>1.2.0</revision>
+                    <junit.version>5.10.0</junit.version>
+                    <gson.version>${gson.base}.1</gson.version>
+                    <gson.base>2.10</gson.base>
+                    <maven.compiler.release>17</maven.compiler.release>
+                    <exec.mainClass>${project.groupId}.App</exec.mainClass>
+                </properties>
+                <dependencies>
+                    <dependency>
+                        <groupId>com.google.code.gson</groupId>
+                        <artifactId>gson</artifactId>
+                        <version>${gson.version}</version>
+                    </dependency>
+                    <dependency>
+                        <groupId>${project.groupId}</groupId>
+                        <artifactId>util</artifactId>
+                        <version>${project.version}</version>
+                    </dependency>
+                </dependencies>
+            </project>
+        "#;
+        let output = parse(content).unwrap();
+
+        assert_eq!(output.version.as_deref(), Some("1.2.0"));
+        assert_eq!(output.jvm_target.as_deref(), Some("17"));
+        assert_eq!(output.main_class.as_deref(), Some("com.foo.App"));
+        assert_eq!(
+            coordinates(&output),
+            ["com.google.code.gson:gson:2.10.1", "com.foo:util:1.2.0"]
+        );
+    }
+
+    #[test]
+    fn unknown_properties_are_untranslated() {
+        let content = r#"
+            <project>
+                <artifactId>app</artifactId>
+                <dependencies>
+                    <dependency>
+                        <groupId>org.slf4j</groupId>
+                        <artifactId>slf4j-api</artifactId>
+                        <version>${slf4j.version}</version>
+                    </dependency>
+                </dependencies>
+            </project>
+        "#;
+        let output = parse(content).unwrap();
+
+        assert!(coordinates(&output).is_empty());
+        assert_eq!(
+            output.untranslated,
+            ["dependency `org.slf4j:slf4j-api` (unknown version)"]
+        );
+    }
+
+    #[test]
+    fn self_references_are_not_resolved() {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("a"), String::from("${a}"));
+        assert_eq!(resolve("${a}", &properties), None);
+    }
+}
@@ -0,0 +1,7 @@
+mod gradle;
+mod main;
+mod maven;
+mod reports;
+mod utils;
+
+pub use main::main as import_project;
@@ -0,0 +1,108 @@
+use crate::core::reporting::{messages, KojampReport, ReportType};
+use colored::Colorize;
+
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const KOJAMP_TOML_ALREADY_EXISTS: &str = "Kojamp.toml already exists";
+const NO_BUILD_FILE_FOUND: &str = "No build file found";
+const COULD_NOT_READ_FILE: &str = "Couldn't read `$$$`";
+const INVALID_BUILD_FILE: &str = "Invalid `$$$` file";
+const INVALID_PROJECT_NAME: &str = "Invalid project name";
+const SOURCE_DIR_IS_MISSING: &str = "Couldn't find `$$$` dir";
+const MAIN_CLASS_NOT_FOUND: &str = "Main class not found";
+const UNTRANSLATED_SETTING: &str = "Untranslated setting";
+const COULD_NOT_WRITE_KOJAMP_TOML: &str = "Couldn't write the Kojamp.toml file";
+const PROJECT_IMPORTED: &str = "`$$$` project imported";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn kojamp_toml_already_exists() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        KOJAMP_TOML_ALREADY_EXISTS,
+        messages::kojamp_toml_already_exists(),
+    )
+}
+
+pub fn no_build_file_found() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NO_BUILD_FILE_FOUND,
+        messages::no_build_file_found(),
+    )
+}
+
+pub fn could_not_read_file(file: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_READ_FILE.replace("$$$", file),
+        messages::empty_message(),
+    )
+}
+
+pub fn invalid_build_file(file: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        INVALID_BUILD_FILE.replace("$$$", file),
+        messages::invalid_build_file(file),
+    )
+}
+
+pub fn invalid_project_name(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        INVALID_PROJECT_NAME,
+        messages::invalid_imported_project_name(name),
+    )
+}
+
+pub fn source_dir_is_missing(source_dir: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        SOURCE_DIR_IS_MISSING.replace("$$$", source_dir),
+        messages::imported_source_dir_is_missing(),
+    )
+}
+
+pub fn main_class_not_found(name: &str, kind: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        MAIN_CLASS_NOT_FOUND,
+        messages::main_class_not_found(name, kind),
+    )
+}
+
+pub fn untranslated_setting(setting: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        UNTRANSLATED_SETTING,
+        messages::untranslated_setting(setting),
+    )
+}
+
+pub fn could_not_write_kojamp_toml() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_WRITE_KOJAMP_TOML,
+        messages::empty_message(),
+    )
+}
+
+pub fn success_report(
+    name: &str,
+    build_file: &str,
+    dependency_count: usize,
+    source_dir: &str,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        PROJECT_IMPORTED.replace("$$$", &format!("{}", name.bright_green())),
+        messages::project_imported(build_file, dependency_count, source_dir),
+    )
+}
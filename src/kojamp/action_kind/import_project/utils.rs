@@ -0,0 +1,149 @@
+use super::super::new_project::content;
+use crate::{
+    core::{
+        consts::files_and_dirs::JAR_FILE_EXTENSION,
+        models::{ProjectAuthor, ProjectDependency, DEFAULT_PROJECT_VERSION},
+    },
+    utils::string::StringTransformation,
+};
+use std::path::Path;
+use toml_edit::{value, DocumentMut, Item, Table};
+
+pub const MAVEN_BUILD_FILE: &str = "pom.xml";
+pub const GRADLE_BUILD_FILES: [&str; 2] = ["build.gradle.kts", "build.gradle"];
+pub const GRADLE_SETTINGS_FILES: [&str; 2] =
+    ["settings.gradle.kts", "settings.gradle"];
+
+// what could be read from the foreign build file (settings that couldn't be
+// translated are kept as plain descriptions)
+#[derive(Default)]
+pub struct ImportedProject {
+    pub name: Option<String>,
+    pub kind: Option<String>,
+    pub group: Option<String>,
+    pub version: Option<String>,
+    pub main_class: Option<String>,
+    pub source_dirs: Vec<String>,
+    pub jvm_target: Option<String>,
+    pub authors: Vec<ProjectAuthor>,
+    pub dependencies: Vec<(String, ProjectDependency)>,
+    pub untranslated: Vec<String>,
+}
+
+impl ImportedProject {
+    // the same key can't be used twice at the `[dependencies]` table
+    pub fn add_dependency(&mut self, dependency: ProjectDependency) {
+        let name = match (&dependency, dependency.default_name()) {
+            (
+                ProjectDependency::Maven {
+                    group, artifact, ..
+                },
+                _,
+            ) if self.dependencies.iter().any(|(n, _)| n == artifact) => {
+                format!("{}.{}", group, artifact)
+            }
+            (_, Some(name)) => name,
+            (_, None) => {
+                return self
+                    .untranslated
+                    .push(String::from("unnamed dependency"))
+            }
+        };
+        self.dependencies.push((name, dependency));
+    }
+
+    pub fn untranslated<T: AsRef<str>>(&mut self, description: T) {
+        self.untranslated.push(description.as_ref().to_string());
+    }
+}
+
+// `1.8` and `8` are the same jvm target
+pub fn normalize_jvm_target(target: &str) -> String {
+    let target = target.trim();
+    target.strip_prefix("1.").unwrap_or(target).to_string()
+}
+
+pub fn count_sources(dir: &Path, extension: &str) -> usize {
+    let entries = match dir.read_dir() {
+        Ok(e) => e,
+        Err(_) => return 0,
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .map(|p| match p.is_dir() {
+            true => count_sources(&p, extension),
+            false => p.extension().is_some_and(|e| e == extension) as usize,
+        })
+        .sum()
+}
+
+pub fn default_source_dirs(kind: &str) -> [&'static str; 2] {
+    match kind {
+        "kotlin" => ["src/main/kotlin", "src/main/java"],
+        _ => ["src/main/java", "src/main/kotlin"],
+    }
+}
+
+// the maven/gradle resources dir (only kept when it's there)
+pub const DEFAULT_RESOURCES_DIR: &str = "src/main/resources";
+
+// `my-app` -> `MyApp` (kojamp project names are camel case)
+pub fn project_name(raw: &str) -> String {
+    raw.to_valid_camel_case()
+}
+
+pub fn dependency_path(path: &str) -> Option<ProjectDependency> {
+    let path = path.trim().trim_start_matches("./");
+    Some(ProjectDependency::Path(path.into()))
+        .filter(|_| path.ends_with(&format!(".{}", JAR_FILE_EXTENSION)))
+}
+
+pub fn kojamp_toml(
+    project: &ImportedProject,
+    name: &str,
+    kind: &str,
+    source_dir: &str,
+    resources_dir: Option<&str>,
+) -> String {
+    let base = content::toml(name, kind, Some(&project.authors), false);
+    let mut document = base.parse::<DocumentMut>().unwrap_or_default();
+
+    let project_table = &mut document["project"];
+    project_table["version"] = value(
+        project
+            .version
+            .clone()
+            .unwrap_or(DEFAULT_PROJECT_VERSION.to_string()),
+    );
+    if let Some(group) = &project.group {
+        project_table["group"] = value(group);
+    }
+    if let Some(main_class) = &project.main_class {
+        project_table["main-class"] = value(main_class);
+    }
+
+    let mut build = Table::new();
+    build["source-dir"] = value(source_dir);
+    if let Some(dir) = resources_dir {
+        build["resources-dir"] = value(dir);
+    }
+    if let Some(target) = &project.jvm_target {
+        match target.parse::<i64>() {
+            Ok(t) => build["jvm-target"] = value(t),
+            Err(_) => build["jvm-target"] = value(target),
+        }
+    }
+    document["build"] = Item::Table(build);
+
+    if !project.dependencies.is_empty() {
+        let mut dependencies = Table::new();
+        for (name, dep) in project.dependencies.iter() {
+            dependencies[name.as_str()] = value(dep.to_toml_value());
+        }
+        document["dependencies"] = Item::Table(dependencies);
+    }
+
+    document.to_string()
+}
@@ -0,0 +1,166 @@
+use super::reports::*;
+use crate::{
+    core::{
+        models::{ProjectDependency, ProjectGraph},
+        reporting::KojampReport,
+    },
+    kojamp::action::build_project::utils::output_entry,
+};
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+};
+use zip::ZipArchive;
+
+// multi release and module descriptors are expected to repeat
+fn is_checked_class(entry: &str) -> bool {
+    entry.ends_with(".class")
+        && !entry.starts_with("META-INF/")
+        && !entry.ends_with("module-info.class")
+}
+
+fn class_name(entry: &str) -> String {
+    entry.trim_end_matches(".class").replace(['/', '\\'], ".")
+}
+
+fn dir_classes(root: &Path, dir: &Path, output: &mut Vec<String>) {
+    let entries = match dir.read_dir() {
+        Ok(e) => e,
+        Err(_) => return,
+    };
+
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            dir_classes(root, &path, output);
+            continue;
+        }
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if is_checked_class(&relative) {
+            output.push(class_name(&relative));
+        }
+    }
+}
+
+fn jar_classes(jar: &Path) -> Result<Vec<String>, ()> {
+    let file = File::open(jar).map_err(|_| ())?;
+    let archive = ZipArchive::new(file).map_err(|_| ())?;
+    Ok(archive
+        .file_names()
+        .filter(|e| is_checked_class(e))
+        .map(class_name)
+        .collect())
+}
+
+// maven jars are shown by their file name, other paths as they are
+fn display_path(path: &Path) -> String {
+    let from_repository = ProjectDependency::maven_repository()
+        .is_some_and(|r| path.starts_with(r));
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|c| path.strip_prefix(c).ok().map(Path::to_path_buf));
+
+    match (from_repository, relative) {
+        (true, _) => path
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        (_, Some(r)) => r.to_string_lossy().to_string(),
+        _ => path.to_string_lossy().to_string(),
+    }
+}
+
+// the project output plus its classpath (dependency outputs and jars)
+fn classpath<F: Fn(usize) -> PathBuf>(
+    graph: &ProjectGraph,
+    index: usize,
+    out_dir: F,
+) -> (Vec<PathBuf>, Vec<KojampReport>) {
+    let manifest = graph.get_node(index).get_manifest();
+    let entry = |i: usize| {
+        let dep = graph.get_node(i).get_manifest();
+        output_entry(&out_dir(i), dep.get_name(), dep.get_kind())
+    };
+
+    let mut output: Vec<KojampReport> = Vec::new();
+    let own_entry = entry(index);
+    if !own_entry.exists() {
+        output.push(project_not_built(manifest.get_name()));
+    }
+
+    let (dependencies, missing) = graph.classpath(index, entry);
+    output.extend(missing.iter().map(|m| missing_dependency(m)));
+
+    let entries = [own_entry]
+        .into_iter()
+        .chain(dependencies)
+        .filter(|e| e.exists())
+        .collect();
+    (entries, output)
+}
+
+pub fn run<F: Fn(usize) -> PathBuf>(
+    graph: &ProjectGraph,
+    targets: &[usize],
+    out_dir: F,
+) -> Vec<KojampReport> {
+    let mut output: Vec<KojampReport> = Vec::new();
+
+    for index in targets.iter() {
+        let name = graph.get_node(*index).get_manifest().get_name();
+        let (entries, reports) = classpath(graph, *index, &out_dir);
+        output.extend(reports);
+
+        // class name to the classpath entries defining it
+        let mut classes: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, entry) in entries.iter().enumerate() {
+            let names = match entry.is_dir() {
+                true => {
+                    let mut names = Vec::new();
+                    dir_classes(entry, entry, &mut names);
+                    names
+                }
+                false => match jar_classes(entry) {
+                    Ok(n) => n,
+                    Err(_) => {
+                        output.push(unreadable_jar(&display_path(entry)));
+                        continue;
+                    }
+                },
+            };
+            for class in names {
+                let defined_by = classes.entry(class).or_default();
+                if !defined_by.contains(&i) {
+                    defined_by.push(i);
+                }
+            }
+        }
+
+        // duplicates are grouped by the entries sharing them
+        let mut conflicts: HashMap<Vec<usize>, Vec<String>> = HashMap::new();
+        for (class, defined_by) in classes.into_iter() {
+            if defined_by.len() > 1 {
+                conflicts.entry(defined_by).or_default().push(class);
+            }
+        }
+        let mut conflicts: Vec<(Vec<usize>, Vec<String>)> =
+            conflicts.into_iter().collect();
+        conflicts.sort();
+
+        if conflicts.is_empty() {
+            output.push(no_duplicate_classes(name, entries.len()));
+        }
+        for (defined_by, mut classes) in conflicts {
+            classes.sort();
+            let paths: Vec<String> = defined_by
+                .iter()
+                .map(|i| display_path(&entries[*i]))
+                .collect();
+            output.push(duplicate_classes(&paths, &classes));
+        }
+    }
+
+    output
+}
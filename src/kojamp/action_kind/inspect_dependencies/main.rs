@@ -0,0 +1,23 @@
+use super::{check, reports::*, tree};
+use crate::core::{models::ProjectWorkspace, reporting::KojampReport};
+use clap::ArgMatches;
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let (command, matching) = matching.subcommand().unwrap();
+    let package = matching.get_one::<String>("package");
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+
+    let (workspace, graph, targets) =
+        ProjectWorkspace::targets(&curdir, package.map(String::as_str))
+            .map_err(|e| vec![target_error(e)])?;
+
+    let out_dir = |index: usize| graph.out_dir(index, workspace.as_ref());
+
+    match command {
+        "tree" => Ok(tree::print(&graph, &targets)),
+        _ => Ok(check::run(&graph, &targets, out_dir)),
+    }
+}
@@ -0,0 +1,6 @@
+mod check;
+mod main;
+mod reports;
+mod tree;
+
+pub use main::main as inspect_dependencies;
@@ -0,0 +1,138 @@
+use crate::core::{
+    models::{GraphError, ManifestError, TargetError},
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const NOT_A_WORKSPACE: &str = "Not a workspace";
+const UNKNOWN_MEMBER: &str = "Unknown workspace member";
+const INVALID_PROJECT_DEPENDENCY: &str = "Invalid project at `$$$`";
+const DEPENDENCY_CYCLE: &str = "Dependency cycle";
+const MISSING_DEPENDENCY: &str = "Missing dependency";
+const PROJECT_NOT_BUILT: &str = "The `$$$` project wasn't built yet";
+const UNREADABLE_JAR: &str = "Couldn't read `$$$`";
+const DUPLICATE_CLASSES: &str = "Duplicate classes at the classpath";
+const NO_DUPLICATE_CLASSES: &str =
+    "No duplicate classes at the `$$$` classpath";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => KojampReport::new(
+            ReportType::Error,
+            PROBABLY_A_NON_KOJAMP_PROJECT,
+            messages::kojamp_toml_not_found(),
+        ),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn not_a_workspace() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NOT_A_WORKSPACE,
+        messages::not_a_workspace(),
+    )
+}
+
+pub fn unknown_member(name: &str, members: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNKNOWN_MEMBER,
+        messages::unknown_workspace_member(name, members),
+    )
+}
+
+pub fn target_error(error: TargetError) -> KojampReport {
+    match error {
+        TargetError::Manifest(e) => manifest_error(e),
+        TargetError::NotAWorkspace => not_a_workspace(),
+        TargetError::Graph(e) => graph_error(e),
+        TargetError::UnknownMember(name, members) => {
+            unknown_member(&name, &members)
+        }
+    }
+}
+
+pub fn graph_error(error: GraphError) -> KojampReport {
+    match error {
+        GraphError::Manifest(path, e) => KojampReport::new(
+            ReportType::Error,
+            INVALID_PROJECT_DEPENDENCY.replace("$$$", &path.to_string_lossy()),
+            match e {
+                ManifestError::Missing => messages::kojamp_toml_not_found(),
+                ManifestError::Unreadable => {
+                    messages::toml_file_could_not_be_read()
+                }
+                ManifestError::Invalid => messages::invalid_toml_file(),
+            },
+        ),
+        GraphError::Cycle(cycle) => KojampReport::new(
+            ReportType::Error,
+            DEPENDENCY_CYCLE,
+            messages::dependency_cycle(&cycle),
+        ),
+    }
+}
+
+pub fn missing_dependency(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        MISSING_DEPENDENCY,
+        messages::missing_dependency(name),
+    )
+}
+
+pub fn project_not_built(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        PROJECT_NOT_BUILT.replace("$$$", &format!("{}", name.bright_yellow())),
+        messages::project_output_not_checked(),
+    )
+}
+
+pub fn unreadable_jar(jar: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        UNREADABLE_JAR.replace("$$$", jar),
+        messages::unreadable_jar(),
+    )
+}
+
+pub fn duplicate_classes(paths: &[String], classes: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        DUPLICATE_CLASSES,
+        messages::duplicate_classes(paths, classes),
+    )
+}
+
+pub fn no_duplicate_classes(name: &str, entries: usize) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        NO_DUPLICATE_CLASSES
+            .replace("$$$", &format!("{}", name.bright_green())),
+        messages::classpath_entries_checked(entries),
+    )
+}
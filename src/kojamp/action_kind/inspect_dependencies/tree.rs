@@ -0,0 +1,107 @@
+use super::reports::*;
+use crate::core::{
+    models::{
+        DependencyNode, DependencyTree, NodeState, ProjectDependency,
+        ProjectGraph,
+    },
+    reporting::KojampReport,
+};
+use colored::Colorize;
+
+fn label(node: &DependencyNode) -> String {
+    let name = match node.get_dependency() {
+        ProjectDependency::Maven {
+            group,
+            artifact,
+            version,
+        } => format!("{}:{}:{}", group, artifact, version),
+        ProjectDependency::Path(path) if node.is_project() => format!(
+            "{} v{} ({})",
+            node.get_name(),
+            node.get_version().unwrap_or_default(),
+            path.to_string_lossy()
+        ),
+        ProjectDependency::Path(path) => {
+            format!("{} ({})", node.get_name(), path.to_string_lossy())
+        }
+    };
+
+    match node.get_state() {
+        NodeState::Resolved if node.is_project() => {
+            format!("{}", name.bright_cyan())
+        }
+        NodeState::Resolved => name,
+        NodeState::Missing => {
+            format!("{} {}", name.bright_red(), "(missing)".bright_red())
+        }
+        NodeState::Evicted(winner) => format!(
+            "{} {}",
+            name.bright_black(),
+            format!("(evicted by {})", winner).bright_yellow()
+        ),
+        NodeState::Omitted => format!("{} {}", name, "(*)".bright_black()),
+    }
+}
+
+fn print_children(tree: &DependencyTree, children: &[usize], prefix: &str) {
+    for (i, child) in children.iter().enumerate() {
+        let last = i + 1 == children.len();
+        let node = tree.get_node(*child);
+        println!(
+            "{}{} {}",
+            prefix,
+            if last { "└──" } else { "├──" },
+            label(node)
+        );
+        let prefix =
+            format!("{}{}", prefix, if last { "    " } else { "│   " });
+        print_children(tree, node.get_children(), &prefix);
+    }
+}
+
+// nearer versions win, so the evicted ones are shown (but not expanded)
+pub fn print(graph: &ProjectGraph, targets: &[usize]) -> Vec<KojampReport> {
+    let mut output: Vec<KojampReport> = Vec::new();
+    let (mut omitted, mut evicted) = (false, false);
+
+    for (i, index) in targets.iter().enumerate() {
+        let node = graph.get_node(*index);
+        let manifest = node.get_manifest();
+        let tree = DependencyTree::resolve(node.get_root(), manifest);
+
+        if i > 0 {
+            println!();
+        }
+        println!(
+            "{} v{}",
+            manifest.get_name().bright_green(),
+            manifest.get_version()
+        );
+        print_children(&tree, tree.get_roots(), "");
+
+        for dep in tree.get_nodes() {
+            match dep.get_state() {
+                NodeState::Omitted => omitted = true,
+                NodeState::Evicted(_) => evicted = true,
+                _ => {}
+            }
+        }
+        output.extend(tree.missing().iter().map(|m| missing_dependency(m)));
+    }
+
+    if omitted || evicted {
+        println!();
+    }
+    if omitted {
+        println!("{}", "(*) already listed above".bright_black());
+    }
+    if evicted {
+        println!(
+            "{}",
+            "evicted versions lost to nearer ones and aren't at the classpath"
+                .bright_black()
+        );
+    }
+
+    output
+}
@@ -0,0 +1,120 @@
+use super::{reports::*, utils::*};
+use crate::{
+    core::{
+        consts::files_and_dirs::{JAR_FILE_EXTENSION, OUT_DIR},
+        models::{ProjectDependency, ProjectManifest},
+        reporting::KojampReport,
+    },
+    kojamp::{
+        action::{
+            build_project,
+            build_project::utils::{module_names, pack_classes},
+        },
+        subcommands,
+    },
+};
+use clap::ArgMatches;
+use std::fs;
+
+pub fn main(
+    _matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+    let manifest =
+        ProjectManifest::load(&curdir).map_err(|e| vec![manifest_error(e)])?;
+    let repository = ProjectDependency::maven_repository()
+        .ok_or_else(|| vec![maven_repository_not_found()])?;
+
+    // kotlin applications are built along with the kotlin runtime, which
+    // doesn't belong to a published jar
+    if manifest.get_kind() == "kotlin" && !manifest.is_library() {
+        return Err(vec![kotlin_application_not_installable()]);
+    }
+    // many modules are built apart, so there's no single jar to publish
+    if module_names(&curdir.join(manifest.get_source_dir())).len() > 1 {
+        return Err(vec![project_with_many_modules(manifest.get_name())]);
+    }
+
+    // the jar is always built from fresh outputs
+    let build_matching = subcommands::build::cmd()
+        .try_get_matches_from(["build"])
+        .map_err(|_| vec![install_undefined_error()])?;
+    let mut output = build_project(build_matching)?;
+
+    let mut dependencies: Vec<PomDependency> = Vec::new();
+    for entry in manifest.get_dependencies() {
+        match entry {
+            Err(name) => output.push(invalid_dependency_entry(&name)),
+            Ok((
+                _,
+                ProjectDependency::Maven {
+                    group,
+                    artifact,
+                    version,
+                },
+            )) => dependencies.push(PomDependency {
+                group,
+                artifact,
+                version,
+            }),
+            Ok((name, ProjectDependency::Path(p)))
+                if p.extension().is_some_and(|e| e == JAR_FILE_EXTENSION) =>
+            {
+                output.push(jar_dependency_left_out(&name))
+            }
+            // kojamp projects are referenced by their own coordinate
+            Ok((name, ProjectDependency::Path(p))) => {
+                match ProjectManifest::load(&curdir.join(&p)) {
+                    Ok(dep) => {
+                        output.push(project_dependency_note(&name));
+                        dependencies.push(PomDependency {
+                            group: dep.get_group(),
+                            artifact: dep.get_artifact(),
+                            version: dep.get_version().to_string(),
+                        });
+                    }
+                    Err(_) => output.push(untranslated_dependency(&name)),
+                }
+            }
+        }
+    }
+
+    let out_dir = curdir.join(OUT_DIR);
+    let jar = out_dir.join(manifest.get_library_jar());
+
+    // libraries are already packed by the build
+    if !manifest.is_library() {
+        let main_class = manifest.get_main_class();
+        let _ = fs::remove_file(&jar);
+        pack_classes(&out_dir, &jar, Some(&main_class))
+            .map_err(|_| vec![could_not_pack_jar(&jar)])?;
+    }
+
+    let target = artifact_dir(&repository, &manifest);
+    fs::create_dir_all(&target)
+        .map_err(|_| vec![could_not_write_file(&target)])?;
+
+    let jar_content =
+        fs::read(&jar).map_err(|_| vec![could_not_pack_jar(&jar)])?;
+    let installed_jar = target.join(manifest.get_library_jar());
+    write_with_checksums(&installed_jar, &jar_content)
+        .map_err(|f| vec![could_not_write_file(&f)])?;
+    write_with_checksums(
+        &installed_jar.with_extension("pom"),
+        pom(&manifest, &dependencies).as_bytes(),
+    )
+    .map_err(|f| vec![could_not_write_file(&f)])?;
+
+    // the metadata lives at the artifact dir (shared by every version)
+    let metadata_file = target
+        .parent()
+        .map(|p| p.join(MAVEN_METADATA_FILE))
+        .unwrap_or_default();
+    let previous = fs::read_to_string(&metadata_file).ok();
+    fs::write(&metadata_file, metadata(&manifest, previous.as_deref()))
+        .map_err(|_| vec![could_not_write_file(&metadata_file)])?;
+
+    output.push(success_report(&manifest, &target));
+    Ok(output)
+}
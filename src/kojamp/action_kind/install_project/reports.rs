@@ -0,0 +1,148 @@
+use crate::core::{
+    models::{ManifestError, ProjectManifest},
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+use std::path::Path;
+
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const MAVEN_REPOSITORY_NOT_FOUND: &str = "Local maven repository not found";
+const KOTLIN_APPLICATION: &str = "Kotlin applications can't be installed";
+const PROJECT_WITH_MANY_MODULES: &str =
+    "Projects with many modules can't be installed";
+const INVALID_DEPENDENCY_ENTRY: &str = "Invalid dependency entry";
+const DEPENDENCY_LEFT_OUT: &str = "Dependency left out of the pom";
+const PROJECT_DEPENDENCY: &str = "`$$$` is a kojamp project dependency";
+const COULD_NOT_PACK_JAR: &str = "Couldn't pack the project jar";
+const COULD_NOT_WRITE_FILE: &str = "Couldn't write `$$$`";
+const PROJECT_INSTALLED: &str = "Project installed as $$$";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => KojampReport::new(
+            ReportType::Error,
+            PROBABLY_A_NON_KOJAMP_PROJECT,
+            messages::kojamp_toml_not_found(),
+        ),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn install_undefined_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        "Undefined error",
+        messages::main_app_undefined_error(),
+    )
+}
+
+pub fn maven_repository_not_found() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        MAVEN_REPOSITORY_NOT_FOUND,
+        messages::maven_repository_not_found(),
+    )
+}
+
+pub fn kotlin_application_not_installable() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        KOTLIN_APPLICATION,
+        messages::kotlin_application_not_installable(),
+    )
+}
+
+pub fn project_with_many_modules(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        PROJECT_WITH_MANY_MODULES,
+        messages::project_with_many_modules(name),
+    )
+}
+
+pub fn invalid_dependency_entry(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        INVALID_DEPENDENCY_ENTRY,
+        messages::invalid_dependency_entry(name),
+    )
+}
+
+pub fn jar_dependency_left_out(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        DEPENDENCY_LEFT_OUT,
+        messages::dependency_left_out_of_pom(name),
+    )
+}
+
+pub fn untranslated_dependency(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        DEPENDENCY_LEFT_OUT,
+        messages::dependency_not_exported(name),
+    )
+}
+
+pub fn project_dependency_note(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        PROJECT_DEPENDENCY.replace("$$$", &format!("{}", name.bright_yellow())),
+        messages::exported_project_dependency(),
+    )
+}
+
+pub fn could_not_pack_jar(jar: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_PACK_JAR,
+        messages::could_not_pack_jar(&jar.to_string_lossy()),
+    )
+}
+
+pub fn could_not_write_file(file: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_WRITE_FILE.replace("$$$", &file.to_string_lossy()),
+        messages::could_not_write_to_repository(&file.to_string_lossy()),
+    )
+}
+
+pub fn success_report(
+    manifest: &ProjectManifest,
+    target: &Path,
+) -> KojampReport {
+    let coordinate = format!(
+        "{}:{}:{}",
+        manifest.get_group(),
+        manifest.get_artifact(),
+        manifest.get_version()
+    );
+    KojampReport::new(
+        ReportType::Success,
+        PROJECT_INSTALLED
+            .replace("$$$", &format!("{}", coordinate.bright_green())),
+        messages::project_installed(&coordinate, &target.to_string_lossy()),
+    )
+}
@@ -0,0 +1,158 @@
+use crate::{core::models::ProjectManifest, utils::time::UtcTime};
+use md5::Md5;
+use sha1::{Digest, Sha1};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const MAVEN_METADATA_FILE: &str = "maven-metadata-local.xml";
+
+pub struct PomDependency {
+    pub group: String,
+    pub artifact: String,
+    pub version: String,
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// `<group dirs>/<artifact>/<version>` within the local repository
+pub fn artifact_dir(repository: &Path, manifest: &ProjectManifest) -> PathBuf {
+    let mut dir = repository.to_path_buf();
+    dir.extend(manifest.get_group().split('.'));
+    dir.push(manifest.get_artifact());
+    dir.push(manifest.get_version());
+    dir
+}
+
+// `yyyyMMddHHmmss` (UTC), as maven writes it at the metadata file
+pub fn timestamp() -> String {
+    let now = UtcTime::now();
+    format!(
+        "{:04}{:02}{:02}{:02}{:02}{:02}",
+        now.year, now.month, now.day, now.hour, now.minute, now.second
+    )
+}
+
+pub fn pom(
+    manifest: &ProjectManifest,
+    dependencies: &[PomDependency],
+) -> String {
+    let dependency_rows: String = dependencies
+        .iter()
+        .map(|d| {
+            format!(
+                "    <dependency>\n      <groupId>{}</groupId>\n      <artifactId>{}</artifactId>\n      <version>{}</version>\n    </dependency>\n",
+                xml_escape(&d.group),
+                xml_escape(&d.artifact),
+                xml_escape(&d.version)
+            )
+        })
+        .collect();
+    let dependencies = if dependency_rows.is_empty() {
+        dependency_rows
+    } else {
+        format!("\n  <dependencies>\n{}  </dependencies>\n", dependency_rows)
+    };
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0"
+         xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+         xsi:schemaLocation="http://maven.apache.org/POM/4.0.0 https://maven.apache.org/xsd/maven-4.0.0.xsd">
+  <modelVersion>4.0.0</modelVersion>
+
+  <groupId>{}</groupId>
+  <artifactId>{}</artifactId>
+  <version>{}</version>
+  <packaging>jar</packaging>
+  <name>{}</name>
+{}</project>
+"#,
+        xml_escape(&manifest.get_group()),
+        xml_escape(&manifest.get_artifact()),
+        xml_escape(manifest.get_version()),
+        xml_escape(manifest.get_name()),
+        dependencies
+    )
+}
+
+// the already installed versions are kept (the new one is the release)
+pub fn metadata(manifest: &ProjectManifest, previous: Option<&str>) -> String {
+    let version = manifest.get_version().to_string();
+    let mut versions: Vec<String> = previous
+        .and_then(|p| roxmltree::Document::parse(p).ok())
+        .map(|d| {
+            d.descendants()
+                .filter(|n| {
+                    n.has_tag_name("version")
+                        && n.parent()
+                            .is_some_and(|p| p.has_tag_name("versions"))
+                })
+                .filter_map(|n| n.text().map(|t| t.trim().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !versions.contains(&version) {
+        versions.push(version.clone());
+    }
+
+    let version_rows: String = versions
+        .iter()
+        .map(|v| format!("      <version>{}</version>\n", xml_escape(v)))
+        .collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata>
+  <groupId>{}</groupId>
+  <artifactId>{}</artifactId>
+  <versioning>
+    <latest>{}</latest>
+    <release>{}</release>
+    <versions>
+{}    </versions>
+    <lastUpdated>{}</lastUpdated>
+  </versioning>
+</metadata>
+"#,
+        xml_escape(&manifest.get_group()),
+        xml_escape(&manifest.get_artifact()),
+        xml_escape(&version),
+        xml_escape(&version),
+        version_rows,
+        timestamp()
+    )
+}
+
+// the file plus its `.sha1` and `.md5` checksum files
+pub fn write_with_checksums(
+    path: &Path,
+    content: &[u8],
+) -> Result<(), PathBuf> {
+    let sha1 = to_hex(&Sha1::digest(content));
+    let md5 = to_hex(&Md5::digest(content));
+    let with_suffix = |suffix: &str| {
+        let mut name = path.as_os_str().to_os_string();
+        name.push(suffix);
+        PathBuf::from(name)
+    };
+
+    fs::write(path, content).map_err(|_| path.to_path_buf())?;
+    for (file, checksum) in
+        [(with_suffix(".sha1"), sha1), (with_suffix(".md5"), md5)]
+    {
+        fs::write(&file, checksum).map_err(|_| file.clone())?;
+    }
+    Ok(())
+}
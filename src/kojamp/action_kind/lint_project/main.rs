@@ -0,0 +1,256 @@
+use super::{reports::*, utils::*};
+use crate::{
+    core::{
+        models::{
+            DependencyTree, ProjectGraph, ProjectManifest, ProjectWorkspace,
+        },
+        reporting::KojampReport,
+    },
+    utils::{path::display, source, string::StringTransformation},
+};
+use clap::ArgMatches;
+use std::{collections::BTreeMap, fs, path::Path};
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let package = matching.get_one::<String>("package");
+    let deny_warnings = matching.get_one::<String>("deny").is_some();
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+
+    let (_, graph, targets) =
+        ProjectWorkspace::targets(&curdir, package.map(String::as_str))
+            .map_err(|e| vec![target_error(e)])?;
+
+    let mut findings = Findings::default();
+    let mut files = 0;
+    for index in targets {
+        let node = graph.get_node(index);
+        files +=
+            lint(node.get_root(), node.get_manifest(), &curdir, &mut findings)
+                .map_err(|e| vec![e])?;
+        lint_modules(&graph, index, &curdir, &mut findings);
+    }
+
+    let mut output = findings.reports;
+    match (findings.errors, findings.warnings) {
+        (0, w) if w == 0 || !deny_warnings => {}
+        (0, w) => {
+            output.push(warnings_denied(w));
+            return Err(output);
+        }
+        (e, w) => {
+            output.push(lint_failed(e, w));
+            return Err(output);
+        }
+    }
+
+    output.push(lint_passed(files, findings.warnings));
+    Ok(output)
+}
+
+// lints a single project (returning the checked file count)
+fn lint(
+    root: &Path,
+    manifest: &ProjectManifest,
+    base: &Path,
+    findings: &mut Findings,
+) -> Result<usize, KojampReport> {
+    let kind = manifest.get_kind();
+    let source_name = manifest.get_source_dir();
+    let source_dir = root.join(source_name);
+
+    if !source_dir.is_dir() {
+        return Err(src_dir_is_missing(source_name));
+    }
+
+    let sources = source::collect_sources(kind, &source_dir)
+        .map_err(|p| unreadable_src_content(source_name, &p))?;
+    let modules = match kind {
+        "java" => source::find_modules(&source_dir),
+        _ => Vec::new(),
+    };
+    // fully qualified names, mapped to their declaration lines
+    let mut declared: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for file in &sources {
+        let shown = display(file, base);
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => {
+                findings.error(unreadable_source_file(&shown));
+                continue;
+            }
+        };
+        let stem = file
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let summary = source::scan(&content, kind, &stem);
+        let at = |line: usize| format!("{}:{}", shown, line);
+
+        if stem == "module-info" {
+            continue;
+        }
+
+        let (package, package_line) =
+            summary.package.clone().unwrap_or((String::new(), 1));
+        // modules at their own dirs hold their package dirs
+        let package_root = modules
+            .iter()
+            .map(|(dir, _)| dir)
+            .find(|dir| file.starts_with(dir))
+            .unwrap_or(&source_dir);
+        let expected = package_from_dir(package_root, file);
+        if package != expected {
+            findings.warning(package_mismatch(
+                &at(package_line),
+                &package,
+                &expected,
+            ));
+        }
+        if !package.is_empty() && !is_lowercase_package(&package) {
+            findings.warning(naming_convention(
+                &at(package_line),
+                "package",
+                &package,
+                &package.to_lowercase(),
+            ));
+        }
+        if !INFO_FILES.contains(&stem.as_str()) && !is_upper_camel_case(&stem) {
+            findings.warning(naming_convention(
+                &shown,
+                "file",
+                &stem,
+                &stem.as_str().to_valid_camel_case(),
+            ));
+        }
+
+        for declaration in &summary.types {
+            let name = declaration.name.as_str();
+            if !is_upper_camel_case(name) {
+                findings.warning(naming_convention(
+                    &at(declaration.line),
+                    "class",
+                    name,
+                    &name.to_valid_camel_case(),
+                ));
+            }
+            if !declaration.top_level {
+                continue;
+            }
+            // java public classes are bound to their file names
+            if kind == "java" && declaration.public && name != stem {
+                findings
+                    .error(public_class_mismatch(&at(declaration.line), name));
+            }
+            let class = match package.as_str() {
+                "" => name.to_string(),
+                p => format!("{}.{}", p, name),
+            };
+            declared
+                .entry(class)
+                .or_default()
+                .push(at(declaration.line));
+        }
+    }
+
+    for (class, locations) in declared.iter().filter(|(_, l)| l.len() > 1) {
+        findings.error(duplicate_class(class, locations));
+    }
+
+    let outside = source::collect_sources_where(kind, root, &|d| {
+        d != source_dir && source::is_scanned_dir(d)
+    })
+    .unwrap_or_default();
+    for file in outside {
+        findings.warning(source_outside_source_dir(
+            &display(&file, base),
+            source_name,
+        ));
+    }
+
+    Ok(sources.len())
+}
+
+// `requires` entries should be found at the JDK, the project itself or its
+// dependencies (java projects by their declared modules, jars by their
+// module names)
+fn lint_modules(
+    graph: &ProjectGraph,
+    index: usize,
+    base: &Path,
+    findings: &mut Findings,
+) {
+    let node = graph.get_node(index);
+    let manifest = node.get_manifest();
+    let source_dir = node.get_root().join(manifest.get_source_dir());
+    let modules = match manifest.get_kind() {
+        "java" => source::find_modules(&source_dir),
+        _ => return,
+    };
+    if modules.is_empty() {
+        return;
+    }
+
+    let system = system_modules();
+    let mut available: Vec<String> =
+        modules.iter().map(|(_, m)| m.name.clone()).collect();
+    for dep in graph.dependencies_of(index) {
+        let dep_node = graph.get_node(dep);
+        let dep_manifest = dep_node.get_manifest();
+        match dep_manifest.get_kind() {
+            "java" => available.extend(
+                source::find_modules(
+                    &dep_node.get_root().join(dep_manifest.get_source_dir()),
+                )
+                .into_iter()
+                .map(|(_, m)| m.name),
+            ),
+            _ => available.push(automatic_module_name(dep_manifest.get_name())),
+        }
+    }
+    let tree = DependencyTree::resolve(node.get_root(), manifest);
+    available.extend(tree.jars().iter().map(|j| jar_module_name(j)));
+
+    for (dir, module) in modules.iter() {
+        let info = display(&dir.join(source::MODULE_INFO_FILE), base);
+        let dir_name = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if *dir != source_dir && dir_name != module.name {
+            findings.error(module_dir_mismatch(
+                &format!("{}:{}", info, module.line),
+                &module.name,
+                &dir_name,
+            ));
+        }
+
+        for required in module.requires.iter() {
+            let found = available.contains(&required.name)
+                || match &system {
+                    Some(s) => s.contains(&required.name),
+                    // the JDK modules are taken as found when java can't
+                    // be run
+                    None => {
+                        required.name.starts_with("java.")
+                            || required.name.starts_with("jdk.")
+                    }
+                };
+            if found {
+                continue;
+            }
+            let report = module_not_found(
+                &format!("{}:{}", info, required.line),
+                &required.name,
+                required.optional,
+            );
+            match required.optional {
+                true => findings.warning(report),
+                false => findings.error(report),
+            }
+        }
+    }
+}
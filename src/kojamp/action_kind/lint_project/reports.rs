@@ -0,0 +1,230 @@
+use crate::core::{
+    models::{GraphError, ManifestError, TargetError},
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+use std::path::Path;
+
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const NOT_A_WORKSPACE: &str = "Not a workspace";
+const UNKNOWN_MEMBER: &str = "Unknown workspace member";
+const INVALID_PROJECT_DEPENDENCY: &str = "Invalid project at `$$$`";
+const DEPENDENCY_CYCLE: &str = "Dependency cycle";
+const COULD_NOT_FIND_SRC_DIR: &str = "Couldn't find `$$$` dir";
+const UNREADABLE_SRC_CONTENT: &str = "Unreadable src content";
+const UNREADABLE_SOURCE_FILE: &str = "Couldn't read `$$$`";
+const PACKAGE_MISMATCH: &str = "Package doesn't match the dir at `$$$`";
+const PUBLIC_CLASS_MISMATCH: &str =
+    "Public class doesn't match the file name at `$$$`";
+const NAMING_CONVENTION: &str = "Naming convention at `$$$`";
+const SOURCE_OUTSIDE_SOURCE_DIR: &str = "Source file outside the `$$$` dir";
+const DUPLICATE_CLASS: &str = "Duplicate `$$$` class";
+const MODULE_NOT_FOUND: &str = "Required module not found at `$$$`";
+const MODULE_DIR_MISMATCH: &str = "Module doesn't match the dir at `$$$`";
+const LINT_FAILED: &str = "Lint failed";
+const WARNINGS_DENIED: &str = "Lint failed (warnings are denied)";
+const LINT_PASSED: &str = "No lint errors";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => KojampReport::new(
+            ReportType::Error,
+            PROBABLY_A_NON_KOJAMP_PROJECT,
+            messages::kojamp_toml_not_found(),
+        ),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn not_a_workspace() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NOT_A_WORKSPACE,
+        messages::not_a_workspace(),
+    )
+}
+
+pub fn unknown_member(name: &str, members: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNKNOWN_MEMBER,
+        messages::unknown_workspace_member(name, members),
+    )
+}
+
+pub fn target_error(error: TargetError) -> KojampReport {
+    match error {
+        TargetError::Manifest(e) => manifest_error(e),
+        TargetError::NotAWorkspace => not_a_workspace(),
+        TargetError::Graph(e) => graph_error(e),
+        TargetError::UnknownMember(name, members) => {
+            unknown_member(&name, &members)
+        }
+    }
+}
+
+pub fn graph_error(error: GraphError) -> KojampReport {
+    match error {
+        GraphError::Manifest(path, e) => KojampReport::new(
+            ReportType::Error,
+            INVALID_PROJECT_DEPENDENCY.replace("$$$", &path.to_string_lossy()),
+            match e {
+                ManifestError::Missing => messages::kojamp_toml_not_found(),
+                ManifestError::Unreadable => {
+                    messages::toml_file_could_not_be_read()
+                }
+                ManifestError::Invalid => messages::invalid_toml_file(),
+            },
+        ),
+        GraphError::Cycle(cycle) => KojampReport::new(
+            ReportType::Error,
+            DEPENDENCY_CYCLE,
+            messages::dependency_cycle(&cycle),
+        ),
+    }
+}
+
+pub fn src_dir_is_missing(source_dir: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_FIND_SRC_DIR.replace("$$$", source_dir),
+        messages::empty_message(),
+    )
+}
+
+pub fn unreadable_src_content(source_dir: &str, path: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNREADABLE_SRC_CONTENT,
+        messages::unreadable_src_content(source_dir, path),
+    )
+}
+
+pub fn unreadable_source_file(file: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNREADABLE_SOURCE_FILE.replace("$$$", file),
+        messages::empty_message(),
+    )
+}
+
+pub fn package_mismatch(
+    location: &str,
+    declared: &str,
+    expected: &str,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        PACKAGE_MISMATCH.replace("$$$", location),
+        messages::package_doesnt_match_dir(declared, expected),
+    )
+}
+
+pub fn public_class_mismatch(location: &str, class: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        PUBLIC_CLASS_MISMATCH.replace("$$$", location),
+        messages::public_class_doesnt_match_file(class),
+    )
+}
+
+pub fn naming_convention(
+    location: &str,
+    item: &str,
+    name: &str,
+    expected: &str,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        NAMING_CONVENTION.replace("$$$", location),
+        messages::naming_convention(item, name, expected),
+    )
+}
+
+pub fn source_outside_source_dir(file: &str, source_dir: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        SOURCE_OUTSIDE_SOURCE_DIR.replace("$$$", source_dir),
+        messages::source_outside_source_dir(file),
+    )
+}
+
+pub fn duplicate_class(class: &str, locations: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        DUPLICATE_CLASS.replace("$$$", &format!("{}", class.bright_red())),
+        messages::duplicate_class(locations),
+    )
+}
+
+pub fn module_not_found(
+    location: &str,
+    module: &str,
+    optional: bool,
+) -> KojampReport {
+    KojampReport::new(
+        match optional {
+            true => ReportType::Warning,
+            false => ReportType::Error,
+        },
+        MODULE_NOT_FOUND.replace("$$$", location),
+        messages::module_not_found(module, optional),
+    )
+}
+
+pub fn module_dir_mismatch(
+    location: &str,
+    module: &str,
+    dir: &str,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        MODULE_DIR_MISMATCH.replace("$$$", location),
+        messages::module_dir_doesnt_match(module, dir),
+    )
+}
+
+pub fn lint_failed(errors: usize, warnings: usize) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        LINT_FAILED,
+        messages::lint_findings(errors, warnings),
+    )
+}
+
+pub fn warnings_denied(warnings: usize) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        WARNINGS_DENIED,
+        messages::lint_findings(0, warnings),
+    )
+}
+
+pub fn lint_passed(files: usize, warnings: usize) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        LINT_PASSED,
+        messages::lint_passed(files, warnings),
+    )
+}
@@ -0,0 +1,170 @@
+use crate::core::reporting::KojampReport;
+use regex::Regex;
+use std::{
+    path::Path,
+    process::{Command, Stdio},
+};
+
+// `module-info`/`package-info` files follow no naming rule
+pub const INFO_FILES: [&str; 2] = ["module-info", "package-info"];
+
+#[derive(Default)]
+pub struct Findings {
+    pub reports: Vec<KojampReport>,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+impl Findings {
+    pub fn error(&mut self, report: KojampReport) {
+        self.errors += 1;
+        self.reports.push(report);
+    }
+
+    pub fn warning(&mut self, report: KojampReport) {
+        self.warnings += 1;
+        self.reports.push(report);
+    }
+}
+
+// the same rule used by project names (`UpperCamelCase`)
+pub fn is_upper_camel_case(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+pub fn is_lowercase_package(package: &str) -> bool {
+    package.split('.').all(|part| {
+        part.starts_with(|c: char| c.is_ascii_lowercase())
+            && part.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'
+            })
+    })
+}
+
+// the package a file stands for (given its dir under the source dir)
+pub fn package_from_dir(source_dir: &Path, file: &Path) -> String {
+    file.parent()
+        .and_then(|p| p.strip_prefix(source_dir).ok())
+        .map(|p| {
+            p.components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+        .unwrap_or_default()
+}
+
+// `java --list-modules` names (`None` when java can't be run)
+pub fn system_modules() -> Option<Vec<String>> {
+    let result = Command::new("java")
+        .arg("--list-modules")
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|r| r.status.success())?;
+
+    Some(
+        String::from_utf8_lossy(&result.stdout)
+            .lines()
+            .filter_map(|l| l.split('@').next())
+            .map(|m| m.trim().to_string())
+            .filter(|m| !m.is_empty())
+            .collect(),
+    )
+}
+
+// the name the JDK gives to a plain jar at the module path (the version
+// suffix is dropped and non alphanumeric chars become dots)
+pub fn automatic_module_name(file_stem: &str) -> String {
+    let version = Regex::new(r"-(\d+(\.|$))").unwrap();
+    let name = match version.find(file_stem) {
+        Some(m) => &file_stem[..m.start()],
+        None => file_stem,
+    };
+    let dotted = Regex::new(r"[^A-Za-z0-9]+").unwrap().replace_all(name, ".");
+    dotted.trim_matches('.').to_string()
+}
+
+// the declared (or `Automatic-Module-Name`) module of a jar, as described
+// by the `jar` tool, otherwise the automatic one
+pub fn jar_module_name(jar: &Path) -> String {
+    let described = Command::new("jar")
+        .arg("--describe-module")
+        .arg("--file")
+        .arg(jar)
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|r| r.status.success())
+        .and_then(|r| {
+            String::from_utf8_lossy(&r.stdout)
+                .lines()
+                .map(str::trim)
+                .find(|l| {
+                    !l.is_empty() && !l.starts_with("No module descriptor")
+                })
+                // `name[@version] automatic|jar:file:...`
+                .and_then(|l| l.split([' ', '@']).next())
+                .map(String::from)
+        });
+
+    described.unwrap_or_else(|| {
+        let stem = jar
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        automatic_module_name(&stem)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn automatic_names_drop_the_version() {
+        assert_eq!(
+            automatic_module_name("commons-lang3-3.14.0"),
+            "commons.lang3"
+        );
+        assert_eq!(
+            automatic_module_name("kotlin-stdlib-1.9.22"),
+            "kotlin.stdlib"
+        );
+        assert_eq!(automatic_module_name("guava-33.0.0-jre"), "guava");
+        assert_eq!(
+            automatic_module_name("jakarta.inject-api-2.0.1"),
+            "jakarta.inject.api"
+        );
+    }
+
+    #[test]
+    fn automatic_names_are_dotted() {
+        assert_eq!(automatic_module_name("foo_bar--baz"), "foo.bar.baz");
+        assert_eq!(automatic_module_name("-my-lib-"), "my.lib");
+        // a digit not followed by a dot is not a version
+        assert_eq!(automatic_module_name("lib-2x"), "lib.2x");
+    }
+
+    #[test]
+    fn described_jars_drop_the_version() {
+        use std::io::Write;
+        use zip::{write::SimpleFileOptions, ZipWriter};
+
+        let dir = std::env::temp_dir().join("kojamp-module-name-tests");
+        std::fs::create_dir_all(&dir).unwrap();
+        let jar = dir.join("commons-lang3-3.14.0.jar");
+
+        // `jar` describes it as `commons.lang3@3.14.0 automatic`
+        let mut writer = ZipWriter::new(std::fs::File::create(&jar).unwrap());
+        writer
+            .start_file("Empty.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"empty").unwrap();
+        writer.finish().unwrap();
+
+        assert_eq!(jar_module_name(&jar), "commons.lang3");
+    }
+}
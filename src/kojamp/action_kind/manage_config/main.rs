@@ -0,0 +1,78 @@
+use super::reports::*;
+use crate::core::{
+    models::{UserConfig, USER_CONFIG_KEYS},
+    reporting::KojampReport,
+};
+use clap::ArgMatches;
+use colored::Colorize;
+use std::env;
+
+fn ignored_env(config: &UserConfig) -> Vec<KojampReport> {
+    config
+        .get_ignored_env()
+        .iter()
+        .map(|(key, value)| ignored_env_override(key, value))
+        .collect()
+}
+
+fn get(matching: &ArgMatches) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let key = matching.get_one::<String>("key").unwrap();
+    let config = UserConfig::load().map_err(|_| vec![user_config_error()])?;
+    let value = config.get(key).ok_or(vec![unset_key(key)])?;
+    println!("{}", value);
+    Ok(ignored_env(&config))
+}
+
+fn set(matching: &ArgMatches) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let key = matching.get_one::<String>("key").unwrap();
+    let value = matching.get_one::<String>("value").unwrap();
+
+    // env values aren't persisted, so only the file content is loaded
+    let mut config =
+        UserConfig::load_file().map_err(|_| vec![user_config_error()])?;
+    config
+        .set(key, value)
+        .map_err(|_| vec![invalid_value(key, value)])?;
+    let path = config.save().map_err(|_| vec![could_not_save_config()])?;
+
+    Ok(vec![key_updated(key, &path)])
+}
+
+fn list() -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let from_file =
+        UserConfig::load_file().map_err(|_| vec![user_config_error()])?;
+    let config = UserConfig::load().map_err(|_| vec![user_config_error()])?;
+
+    for key in USER_CONFIG_KEYS {
+        let env_var = UserConfig::env_var(key);
+        let ignored = config.get_ignored_env().iter().any(|(k, _)| k == key);
+        let source = if env::var(&env_var).is_ok() && !ignored {
+            env_var
+        } else if from_file.get(key).is_some() {
+            String::from("config file")
+        } else {
+            String::from("unset")
+        };
+        let value = config.get(key).unwrap_or_default();
+
+        println!(
+            "{} = {} {}",
+            key.bright_cyan(),
+            format!("{:?}", value).bright_green(),
+            format!("({})", source).bright_black()
+        );
+    }
+
+    Ok(ignored_env(&config))
+}
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    match matching.subcommand() {
+        Some(("get", m)) => get(m),
+        Some(("set", m)) => set(m),
+        Some(("list", _)) => list(),
+        _ => Err(vec![undefined_error()]),
+    }
+}
@@ -0,0 +1,4 @@
+mod main;
+mod reports;
+
+pub use main::main as manage_config;
@@ -0,0 +1,70 @@
+use crate::core::{
+    models::UserConfig,
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+use std::path::Path;
+
+const COULD_NOT_LOAD_USER_CONFIG: &str = "Couldn't load the user config";
+const IGNORED_ENV_OVERRIDE: &str = "Invalid environment variable";
+const COULD_NOT_SAVE_USER_CONFIG: &str = "Couldn't save the user config";
+const INVALID_CONFIG_VALUE: &str = "Invalid config value";
+const UNSET_CONFIG_KEY: &str = "The `$$$` key isn't set";
+const CONFIG_KEY_UPDATED: &str = "The `$$$` key was updated";
+const UNDEFINED_ERROR: &str = "Undefined error";
+
+pub fn user_config_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_LOAD_USER_CONFIG,
+        messages::user_config_could_not_be_loaded(),
+    )
+}
+
+pub fn could_not_save_config() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_SAVE_USER_CONFIG,
+        messages::could_not_save_user_config(),
+    )
+}
+
+pub fn invalid_value(key: &str, value: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        INVALID_CONFIG_VALUE,
+        messages::invalid_config_value(key, value),
+    )
+}
+
+pub fn unset_key(key: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNSET_CONFIG_KEY.replace("$$$", &format!("{}", key.bright_cyan())),
+        messages::empty_message(),
+    )
+}
+
+pub fn key_updated(key: &str, path: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        CONFIG_KEY_UPDATED.replace("$$$", &format!("{}", key.bright_green())),
+        messages::user_config_saved(path),
+    )
+}
+
+pub fn undefined_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNDEFINED_ERROR,
+        messages::main_app_undefined_error(),
+    )
+}
+
+pub fn ignored_env_override(key: &str, value: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        IGNORED_ENV_OVERRIDE,
+        messages::ignored_env_override(&UserConfig::env_var(key), key, value),
+    )
+}
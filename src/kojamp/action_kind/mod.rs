@@ -1,7 +1,29 @@
+mod add_source;
 mod build_project;
+mod document_project;
+mod edit_dependencies;
+mod export_project;
+mod format_sources;
+mod import_project;
+mod inspect_dependencies;
+mod install_project;
+mod lint_project;
+mod manage_config;
 mod new_project;
+mod package_distribution;
 mod run_project;
 
-pub use build_project::build_project;
+pub use add_source::add_source;
+pub use build_project::{build_project, check_project};
+pub use document_project::document_project;
+pub use edit_dependencies::{add_dependency, remove_dependency};
+pub use export_project::export_project;
+pub use format_sources::format_sources;
+pub use import_project::import_project;
+pub use inspect_dependencies::inspect_dependencies;
+pub use install_project::install_project;
+pub use lint_project::lint_project;
+pub use manage_config::manage_config;
 pub use new_project::new_project;
+pub use package_distribution::package_distribution;
 pub use run_project::run_project;
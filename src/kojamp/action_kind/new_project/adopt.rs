@@ -0,0 +1,321 @@
+use super::{
+    files_and_dirs::{content, initialize_git, plan_gitignore, ScaffoldPlan},
+    reports::*,
+    utils::*,
+};
+use crate::{
+    core::{
+        consts::files_and_dirs::*,
+        contracts::{GetInner, IsValid},
+        models::{
+            ProjectAuthors, ProjectKind, ProjectName, ProjectPath, UserConfig,
+        },
+        reporting::KojampReport,
+    },
+    utils::{source, string::StringTransformation},
+};
+use clap::ArgMatches;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use toml_edit::{value, DocumentMut, Item, Table};
+
+struct SourceFile {
+    path: PathBuf,
+    root: PathBuf,
+    main_class: Option<String>,
+}
+
+// sources are kept relative to the project root
+fn scan_sources(root: &Path, kind: &str) -> Result<Vec<PathBuf>, PathBuf> {
+    let sources =
+        source::collect_sources_where(kind, root, &source::is_scanned_dir)?;
+    Ok(sources
+        .iter()
+        .filter_map(|s| s.strip_prefix(root).ok().map(Path::to_path_buf))
+        .collect())
+}
+
+// the source root is the file dir without the package dirs (when they match)
+fn read_source(root: &Path, path: PathBuf, kind: &str) -> SourceFile {
+    let content = fs::read_to_string(root.join(&path)).unwrap_or_default();
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let summary = source::scan(&content, kind, &stem);
+    let package = summary.package.map(|(p, _)| p);
+
+    let parent = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    let source_root = match &package {
+        Some(p) => {
+            let package_dirs: PathBuf = p.split('.').collect();
+            match parent.ends_with(&package_dirs) {
+                true => parent
+                    .ancestors()
+                    .nth(package_dirs.components().count())
+                    .map(Path::to_path_buf)
+                    .unwrap_or_default(),
+                false => parent,
+            }
+        }
+        None => parent,
+    };
+
+    let main_class = summary.mains.into_iter().next().map(|m| m.class);
+
+    SourceFile {
+        path,
+        root: source_root,
+        main_class,
+    }
+}
+
+fn common_root(sources: &[SourceFile]) -> PathBuf {
+    let mut roots = sources.iter().map(|s| s.root.clone());
+    let first = roots.next().unwrap_or_default();
+    roots.fold(first, |acc, root| {
+        acc.components()
+            .zip(root.components())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect()
+    })
+}
+
+// the main file name is taken from the main class by `kojamp build`
+fn main_simple_name(main_class: &str) -> String {
+    let simple_name = main_class.rsplit('.').next().unwrap_or(main_class);
+    simple_name
+        .strip_suffix("Kt")
+        .unwrap_or(simple_name)
+        .to_string()
+}
+
+fn kojamp_toml(
+    name: &str,
+    kind: &str,
+    authors: Option<&ProjectAuthors>,
+    source_dir: &Path,
+    main_class: Option<&str>,
+    library: bool,
+) -> String {
+    let base =
+        content::toml(name, kind, authors.map(|a| a.get_inner()), library);
+    let mut document = base.parse::<DocumentMut>().unwrap_or_default();
+
+    if let Some(m) = main_class.filter(|m| *m != name) {
+        document["project"]["main-class"] = value(m);
+    }
+
+    if source_dir != Path::new(SRC_DIR) {
+        let mut build = Table::new();
+        build["source-dir"] =
+            value(source_dir.to_string_lossy().replace('\\', "/"));
+        document["build"] = Item::Table(build);
+    }
+
+    document.to_string()
+}
+
+pub fn run(
+    matching: &ArgMatches,
+    config: &UserConfig,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let path = ProjectPath::try_new(true).map_err(|_| vec![path_error1()])?;
+    let root = path.get_inner();
+
+    let mut toml_file = PathBuf::from(PROGRAM_TOML_FILE_NAME);
+    toml_file.set_extension(TOML_FILE_EXTENSION);
+    if root.join(&toml_file).exists() {
+        return Err(vec![already_a_kojamp_project_error()]);
+    }
+
+    let (java_files, kotlin_files) =
+        match (scan_sources(&root, "java"), scan_sources(&root, "kotlin")) {
+            (Ok(java), Ok(kotlin)) => (java, kotlin),
+            _ => return Err(vec![could_not_read_dir_error()]),
+        };
+
+    // an explicit `--kind` wins, otherwise the most common source kind
+    let kind = match ProjectKind::from(matching) {
+        ProjectKind::Undefined if kotlin_files.len() > java_files.len() => {
+            String::from("kotlin")
+        }
+        ProjectKind::Undefined => String::from("java"),
+        k if !k.is_valid() => return Err(vec![kind_error(From::from(&k))]),
+        k => <&str>::from(&k).to_string(),
+    };
+    let (files, ignored) = match kind.as_str() {
+        "java" => (java_files, kotlin_files),
+        _ => (kotlin_files, java_files),
+    };
+
+    if files.is_empty() {
+        return Err(vec![no_sources_to_adopt_error(&kind)]);
+    }
+
+    let mut files = files;
+    files.sort();
+    let sources: Vec<SourceFile> = files
+        .into_iter()
+        .map(|f| read_source(&root, f, &kind))
+        .collect();
+    let mains: Vec<&str> = sources
+        .iter()
+        .filter_map(|s| s.main_class.as_deref())
+        .collect();
+
+    let dir_name = root
+        .canonicalize()
+        .unwrap_or_default()
+        .file_name()
+        .map(|n| n.to_string_lossy().to_valid_camel_case())
+        .unwrap_or_default();
+    let name = match (matching.get_one::<String>("name"), mains.as_slice()) {
+        (Some(n), _) => n.clone(),
+        (None, [single]) => main_simple_name(single),
+        _ => dir_name,
+    };
+    if !ProjectName::from(name.as_str()).is_valid() {
+        return Err(vec![name_error(&name)]);
+    }
+
+    // a main class named after the project is preferred (libraries have
+    // none)
+    let library = matching.get_flag("lib");
+    let main_class = mains
+        .iter()
+        .find(|m| main_simple_name(m) == name)
+        .or(mains.first())
+        .map(|m| m.to_string())
+        .filter(|_| !library);
+
+    let mut output: Vec<KojampReport> = Vec::new();
+    if !ignored.is_empty() {
+        output.push(ignored_sources_warning(ignored.len(), &kind));
+    }
+    match mains.len() {
+        _ if library => {}
+        0 => output.push(no_main_found_warning(&name, &kind)),
+        1 => {}
+        _ => output.push(many_mains_found_warning(
+            &mains,
+            main_class.as_deref().unwrap_or(""),
+        )),
+    }
+
+    let mut plan = ScaffoldPlan::new(&root);
+
+    // loose files (sources at the project root) are moved into `src`,
+    // otherwise their common root is referenced as the source dir
+    let source_dir = match common_root(&sources) {
+        r if r.as_os_str().is_empty() => {
+            let mut dirs: Vec<PathBuf> = Vec::new();
+            for source in
+                sources.iter().filter(|s| !s.path.starts_with(SRC_DIR))
+            {
+                let target = Path::new(SRC_DIR).join(&source.path);
+                for dir in target
+                    .ancestors()
+                    .skip(1)
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev()
+                {
+                    let dir = dir.to_path_buf();
+                    if !dir.as_os_str().is_empty()
+                        && !root.join(&dir).exists()
+                        && !dirs.contains(&dir)
+                    {
+                        plan.create_dir(&dir);
+                        dirs.push(dir);
+                    }
+                }
+                plan.move_file(&source.path, &target);
+            }
+            PathBuf::from(SRC_DIR)
+        }
+        r => r,
+    };
+
+    let authors = ProjectAuthors::try_from(matching)
+        .ok()
+        .or(config.get_authors())
+        .or_else(|| git_config_author().map(ProjectAuthors::from));
+    plan.write_file(
+        &toml_file,
+        kojamp_toml(
+            &name,
+            &kind,
+            authors.as_ref(),
+            &source_dir,
+            main_class.as_deref(),
+            library,
+        ),
+    );
+
+    let git_repo = git_requested(matching, config);
+    let mut readme_file = PathBuf::from(README_FILE_NAME);
+    readme_file.set_extension(MARKDOWN_FILE_EXTENSION);
+    if !root.join(&readme_file).exists() {
+        plan.write_file(
+            readme_file,
+            content::readme(
+                &name,
+                &kind,
+                authors.as_ref().map(|a| a.get_inner()),
+            ),
+        );
+    }
+    let gitignore = plan_gitignore(&root);
+
+    if let Some(x) = plan.conflict() {
+        return Err(vec![dir_file_conflict_error(
+            COULD_NOT_SCAFFOLD_PROJECT,
+            x,
+        )]);
+    }
+
+    if matching.get_flag("dry-run") {
+        if git_repo {
+            plan.extend(gitignore);
+        }
+        output.push(dry_run_report(&name, plan.tree()));
+        return Ok(output);
+    }
+
+    plan.apply().map_err(|x| {
+        vec![dir_file_creation_error(COULD_NOT_SCAFFOLD_PROJECT, &x)]
+    })?;
+
+    // dirs left empty by the moved files (non empty ones can't be removed)
+    for source in sources.iter().filter(|_| source_dir == Path::new(SRC_DIR)) {
+        source
+            .path
+            .ancestors()
+            .skip(1)
+            .filter(|d| !d.as_os_str().is_empty() && !d.starts_with(SRC_DIR))
+            .for_each(|d| {
+                let _ = fs::remove_dir(root.join(d));
+            });
+    }
+
+    if git_repo {
+        match initialize_git(&root) {
+            Some(_) => output.push(git_init_warning()),
+            None => {
+                if let Err(x) = gitignore.apply() {
+                    output.push(dir_file_creation_warning(
+                        COULD_NOT_CREATE_GITIGNORE,
+                        &x,
+                    ));
+                }
+            }
+        }
+    }
+
+    output.push(adopted_report(&name, sources.len(), &source_dir));
+    Ok(output)
+}
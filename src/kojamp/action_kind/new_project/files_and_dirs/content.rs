@@ -1,6 +1,9 @@
-use crate::core::consts::program::{PROGRAM_REPO_URL, PROGRAM_VERSION};
+use crate::core::{
+    consts::program::{PROGRAM_REPO_URL, PROGRAM_VERSION},
+    models::{ProjectAuthor, DEFAULT_PROJECT_VERSION, LIBRARY_PROJECT_TYPE},
+};
 
-fn comment_section() -> String {
+pub fn comment_section() -> String {
     format!(
         "/**
  * This file was generated using kojamp CLI-app
@@ -63,7 +66,90 @@ fun main() {{
     )
 }
 
-pub fn readme(name: &str, kind: &str, authors: Option<Vec<String>>) -> String {
+pub fn java_minimal(class_name: &str) -> String {
+    format!(
+        r#"{}
+
+public class {} {{
+
+    public static void main(String[] args) {{
+    }}
+
+}}
+"#,
+        comment_section(),
+        class_name
+    )
+}
+
+pub fn kotlin_minimal() -> String {
+    format!(
+        r#"{}
+
+fun main() {{
+}}
+"#,
+        comment_section()
+    )
+}
+
+pub fn java_library(class_name: &str, minimal: bool) -> String {
+    let body = if minimal {
+        String::new()
+    } else {
+        String::from(
+            r#"
+    public static String greeting(String name) {
+        return "Hello, " + name + "!";
+    }
+"#,
+        )
+    };
+
+    format!(
+        r#"{}
+
+public class {} {{
+{}
+}}
+"#,
+        comment_section(),
+        class_name,
+        body
+    )
+}
+
+pub fn kotlin_library(object_name: &str, minimal: bool) -> String {
+    let body = if minimal {
+        String::new()
+    } else {
+        String::from(
+            r#"
+    fun greeting(name: String): String {
+        return "Hello, $name!"
+    }
+"#,
+        )
+    };
+
+    format!(
+        r#"{}
+
+object {} {{
+{}
+}}
+"#,
+        comment_section(),
+        object_name,
+        body
+    )
+}
+
+pub fn readme(
+    name: &str,
+    kind: &str,
+    authors: Option<&[ProjectAuthor]>,
+) -> String {
     let builtin_badge = format!(
         "[![built in](https://img.shields.io/badge/built_in-kojamp_{}-blue?)]({})",
         PROGRAM_VERSION, PROGRAM_REPO_URL
@@ -76,17 +162,26 @@ pub fn readme(name: &str, kind: &str, authors: Option<Vec<String>>) -> String {
             _ => "kotlin-blue",
         }
     );
-    let may_authors: String = if let Some(ref aut) = authors {
-        let begin = String::from(" by:\n\n");
-        let authors_list = aut
-            .iter()
-            .map(|a| format!("- {}", a))
-            .collect::<Vec<_>>()
-            .join("\n");
-        begin + authors_list.as_ref()
-    } else {
-        "!".into()
-    };
+    let may_authors: String =
+        if let Some(aut) = authors.filter(|a| !a.is_empty()) {
+            let begin = String::from(" by:\n\n");
+            let authors_list = aut
+                .iter()
+                .map(|a| match a.get_email() {
+                    Some(email) => format!(
+                        "- {} ([{}](mailto:{}))",
+                        a.get_name(),
+                        email,
+                        email
+                    ),
+                    None => format!("- {}", a.get_name()),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            begin + authors_list.as_ref()
+        } else {
+            "!".into()
+        };
 
     format!(
         r#"<div align="center">
@@ -115,7 +210,24 @@ pub fn gitignore() -> &'static str {
     out/"
 }
 
-pub fn toml(name: &str, kind: &str, authors: Option<Vec<String>>) -> String {
+fn toml_author(author: &ProjectAuthor) -> String {
+    let quoted = |s: &str| toml::Value::String(s.into()).to_string();
+    match author.get_email() {
+        Some(email) => format!(
+            "{{ name = {}, email = {} }}",
+            quoted(author.get_name()),
+            quoted(email)
+        ),
+        None => format!("{{ name = {} }}", quoted(author.get_name())),
+    }
+}
+
+pub fn toml(
+    name: &str,
+    kind: &str,
+    authors: Option<&[ProjectAuthor]>,
+    library: bool,
+) -> String {
     format!(
         r#"# This file was created using the kojamp-CLI app.
 # Manual changes aren't encouraged!
@@ -124,13 +236,24 @@ pub fn toml(name: &str, kind: &str, authors: Option<Vec<String>>) -> String {
 
 [project]
 name = "{}"
-kind = "{}"{}"#,
+kind = "{}"{}
+version = "{}"{}
+"#,
         PROGRAM_REPO_URL,
         name,
         kind,
-        if let Some(aut) = authors {
-            let quoted_authors: Vec<String> = aut.iter().map(|a| format!("\"{}\"", a)).collect();
-            format!("\nauthors = [{}]", quoted_authors.join(", "))
+        if library {
+            format!("\ntype = \"{}\"", LIBRARY_PROJECT_TYPE)
+        } else {
+            "".into()
+        },
+        DEFAULT_PROJECT_VERSION,
+        if let Some(aut) = authors.filter(|a| !a.is_empty()) {
+            let entries: Vec<String> = aut
+                .iter()
+                .map(|a| format!("    {},\n", toml_author(a)))
+                .collect();
+            format!("\nauthors = [\n{}]", entries.concat())
         } else {
             "".into()
         }
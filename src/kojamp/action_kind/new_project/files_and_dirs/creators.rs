@@ -1,69 +1,71 @@
-use super::content;
+use super::{content, ScaffoldPlan};
 use crate::core::{
     consts::{commands::*, files_and_dirs::*},
-    contracts::{GetInner, Unpack},
-    models::ProjectFields,
+    contracts::Unpack,
+    models::{ProjectFields, ProjectTemplate},
 };
 use std::{
-    fs,
     path::{Path, PathBuf},
     process::{Command, Stdio},
 };
 
-pub fn create_project_dir(path: &PathBuf) -> Result<(), ()> {
-    fs::create_dir(path).map_err(|_| ())
-}
+pub fn plan_project(
+    path: &Path,
+    fields: &ProjectFields,
+    new_called: bool,
+) -> ScaffoldPlan {
+    let mut plan = ScaffoldPlan::new(path);
+    let (name, kind, authors) = fields.unpack();
 
-pub fn create_src_dir(path: &mut PathBuf) -> Result<(), PathBuf> {
-    path.push(SRC_DIR);
-    fs::create_dir(&path).map_err(|_| path.clone())?;
-    path.pop();
-    Ok(())
-}
+    if new_called {
+        plan.create_dir("");
+    }
 
-pub fn create_main_source_file(path: &mut PathBuf, fields: &ProjectFields) -> Result<(), PathBuf> {
-    let name = fields.get_name().get_inner();
-    let (ext, content) = match From::from(fields.get_kind()) {
-        "java" => (JAVA_FILE_EXTENSION, content::java(name)),
-        _ => (KOTLIN_FILE_EXTENSION, content::kotlin(name)),
+    let minimal = matches!(fields.get_template(), ProjectTemplate::Minimal);
+    let library = fields.is_library();
+    let (ext, content) = match (kind, library, minimal) {
+        ("java", true, _) => {
+            (JAVA_FILE_EXTENSION, content::java_library(name, minimal))
+        }
+        ("java", _, false) => (JAVA_FILE_EXTENSION, content::java(name)),
+        ("java", _, true) => (JAVA_FILE_EXTENSION, content::java_minimal(name)),
+        (_, true, _) => (
+            KOTLIN_FILE_EXTENSION,
+            content::kotlin_library(name, minimal),
+        ),
+        (_, _, false) => (KOTLIN_FILE_EXTENSION, content::kotlin(name)),
+        (_, _, true) => (KOTLIN_FILE_EXTENSION, content::kotlin_minimal()),
     };
+    let mut main_file = PathBuf::from(SRC_DIR).join(name);
+    main_file.set_extension(ext);
 
-    path.push(SRC_DIR);
-    path.push(name);
-    path.set_extension(ext);
-    fs::write(&path, content).map_err(|_| path.clone())?;
-    path.pop();
-    path.pop();
-    Ok(())
-}
-
-pub fn create_toml_file(path: &mut PathBuf, fields: &ProjectFields) -> Result<(), PathBuf> {
-    path.push(PROGRAM_TOML_FILE_NAME);
-    path.set_extension(TOML_FILE_EXTENSION);
-    let (name, kind, authors) = fields.unpack();
+    plan.create_dir(SRC_DIR);
+    plan.write_file(main_file, content);
 
-    let toml_content = content::toml(name, kind, authors);
-    fs::write(&path, toml_content).map_err(|_| path.clone())?;
-    path.pop();
-    Ok(())
-}
+    let mut toml_file = PathBuf::from(PROGRAM_TOML_FILE_NAME);
+    toml_file.set_extension(TOML_FILE_EXTENSION);
+    plan.write_file(toml_file, content::toml(name, kind, authors, library));
 
-pub fn create_readme_file(path: &mut PathBuf, fields: &ProjectFields) -> Option<PathBuf> {
-    path.push(README_FILE_NAME);
-    path.set_extension(MARKDOWN_FILE_EXTENSION);
+    // an existing README (`init --force`) is preserved
+    let mut readme_file = PathBuf::from(README_FILE_NAME);
+    readme_file.set_extension(MARKDOWN_FILE_EXTENSION);
+    if !path.join(&readme_file).exists() {
+        plan.write_file(readme_file, content::readme(name, kind, authors));
+    }
 
-    let abspath = std::env::current_dir().unwrap().join(&path);
+    plan
+}
 
-    if abspath.exists() {
-        path.pop();
-        return None;
+// only applied once the git repo is initialized
+pub fn plan_gitignore(path: &Path) -> ScaffoldPlan {
+    let mut plan = ScaffoldPlan::new(path);
+    if !path.join(GIT_IGNORE_FILE_FULLNAME).exists() {
+        plan.write_file(
+            GIT_IGNORE_FILE_FULLNAME,
+            content::gitignore().to_string(),
+        );
     }
-
-    let (name, kind, authors) = fields.unpack();
-    let readme_content = content::readme(name, kind, authors);
-    let output = fs::write(&path, readme_content).err().map(|_| path.clone());
-    path.pop();
-    output
+    plan
 }
 
 pub fn initialize_git(path: &Path) -> Option<()> {
@@ -80,13 +82,3 @@ pub fn initialize_git(path: &Path) -> Option<()> {
         .err()
         .map(|_| ())
 }
-
-pub fn create_git_ignore(path: &mut PathBuf) -> Option<PathBuf> {
-    path.push(GIT_IGNORE_FILE_FULLNAME);
-    let gitignore_content = content::gitignore();
-    let output = fs::write(&path, gitignore_content)
-        .err()
-        .map(|_| path.clone());
-    path.pop();
-    output
-}
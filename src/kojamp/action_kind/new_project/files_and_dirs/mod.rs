@@ -1,4 +1,6 @@
-mod content;
+pub mod content;
 mod creators;
+mod plan;
 
 pub use creators::*;
+pub use plan::ScaffoldPlan;
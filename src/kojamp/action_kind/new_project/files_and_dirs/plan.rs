@@ -0,0 +1,137 @@
+use colored::Colorize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub enum FileOperation {
+    CreateDir(PathBuf),
+    WriteFile(PathBuf, String),
+    MoveFile(PathBuf, PathBuf),
+}
+
+impl FileOperation {
+    fn target(&self) -> &Path {
+        match self {
+            Self::CreateDir(p)
+            | Self::WriteFile(p, _)
+            | Self::MoveFile(_, p) => p,
+        }
+    }
+
+    fn apply(&self) -> Result<(), ()> {
+        match self {
+            Self::CreateDir(p) => fs::create_dir(p),
+            Self::WriteFile(p, content) => fs::write(p, content),
+            Self::MoveFile(from, to) => fs::rename(from, to),
+        }
+        .map_err(|_| ())
+    }
+
+    fn undo(&self) {
+        let _ = match self {
+            Self::CreateDir(p) => fs::remove_dir(p),
+            Self::WriteFile(p, _) => fs::remove_file(p),
+            Self::MoveFile(from, to) => fs::rename(to, from),
+        };
+    }
+}
+
+pub struct ScaffoldPlan {
+    root: PathBuf,
+    operations: Vec<FileOperation>,
+}
+
+impl ScaffoldPlan {
+    pub fn new(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            operations: Vec::new(),
+        }
+    }
+
+    pub fn create_dir<T: AsRef<Path>>(&mut self, relative: T) {
+        let path = self.root.join(relative);
+        self.operations.push(FileOperation::CreateDir(path));
+    }
+
+    pub fn write_file<T: AsRef<Path>>(&mut self, relative: T, content: String) {
+        let path = self.root.join(relative);
+        self.operations
+            .push(FileOperation::WriteFile(path, content));
+    }
+
+    pub fn move_file<T: AsRef<Path>>(&mut self, from: T, to: T) {
+        let (from, to) = (self.root.join(from), self.root.join(to));
+        self.operations.push(FileOperation::MoveFile(from, to));
+    }
+
+    pub fn extend(&mut self, other: ScaffoldPlan) {
+        self.operations.extend(other.operations);
+    }
+
+    // the first target that already exists (nothing is overwritten)
+    pub fn conflict(&self) -> Option<&Path> {
+        self.operations
+            .iter()
+            .map(|op| op.target())
+            .find(|target| target.exists())
+    }
+
+    // every operation is applied or none of them (the failing target is
+    // returned after rolling back the already applied ones)
+    pub fn apply(&self) -> Result<(), PathBuf> {
+        if let Some(target) = self.conflict() {
+            return Err(target.to_path_buf());
+        }
+
+        for (i, op) in self.operations.iter().enumerate() {
+            if op.apply().is_err() {
+                self.operations[..i]
+                    .iter()
+                    .rev()
+                    .for_each(|done| done.undo());
+                return Err(op.target().to_path_buf());
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn tree(&self) -> String {
+        let mut rows =
+            vec![format!("{}", self.root.to_string_lossy().bright_cyan())];
+        let targets: Vec<(&Path, bool)> = self
+            .operations
+            .iter()
+            .filter(|op| op.target() != self.root)
+            .map(|op| {
+                let relative =
+                    op.target().strip_prefix(&self.root).unwrap_or(op.target());
+                (relative, matches!(op, FileOperation::CreateDir(_)))
+            })
+            .collect();
+
+        for (i, (path, is_dir)) in targets.iter().enumerate() {
+            let depth = path.components().count();
+            let is_last = !targets[i + 1..]
+                .iter()
+                .take_while(|(p, _)| p.components().count() >= depth)
+                .any(|(p, _)| p.components().count() == depth);
+            let indent = "│  ".repeat(depth - 1);
+            let branch = if is_last { "└─ " } else { "├─ " };
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let name = if *is_dir {
+                format!("{}/", name).bright_cyan()
+            } else {
+                name.bright_green()
+            };
+            rows.push(format!("{}{}{}", indent, branch, name));
+        }
+
+        rows.join("\n")
+    }
+}
@@ -1,18 +1,60 @@
-use super::{files_and_dirs::*, reports::*, utils::*};
+use super::{adopt, files_and_dirs::*, reports::*, utils::*, wizard};
 use crate::core::{
     contracts::{AddFrom, GetInner, IsValid},
-    models::{ProjectAuthors, ProjectFields, ProjectKind, ProjectName, ProjectPath},
+    models::{
+        ProjectAuthors, ProjectFields, ProjectKind, ProjectName, ProjectPath,
+        ProjectTemplate, UserConfig,
+    },
     reporting::KojampReport,
 };
 use clap::ArgMatches;
 
 const NOT_ALLOWED_CONTENT: [&str; 3] = ["src", "Kojamp.toml", "out"];
 
-pub fn main(pair: (&str, ArgMatches)) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
-    let (cmd, matching) = (pair.0, &pair.1);
+pub fn main(
+    pair: (&str, ArgMatches),
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let (cmd, mut matching) = pair;
+    let config = UserConfig::load().map_err(|_| vec![user_config_error()])?;
+    let prompt_fields = cmd == "new"
+        && (matching.get_flag("interactive")
+            || matching.get_one::<String>("name").is_none());
+
+    let mut env_warnings: Vec<KojampReport> = config
+        .get_ignored_env()
+        .iter()
+        .map(|(key, value)| ignored_env_override(key, value))
+        .collect();
+
+    if cmd == "init" && matching.get_flag("adopt") {
+        return adopt::run(&matching, &config).map(|output| {
+            env_warnings.extend(output);
+            env_warnings
+        });
+    }
+
+    if prompt_fields {
+        matching = match wizard::run(&matching, &config)? {
+            Some(m) => m,
+            None => return Ok(vec![creation_canceled_warning()]),
+        };
+    }
+
+    let matching = &matching;
     let name = ProjectName::from(matching);
-    let kind = ProjectKind::from(matching);
-    let git_repo = matching.get_flag("no-git");
+    let kind = match ProjectKind::from(matching) {
+        ProjectKind::Undefined => config.get_kind(),
+        k => k,
+    };
+    let template = ProjectTemplate::try_from(matching)
+        .ok()
+        .or(config.get_template())
+        .unwrap_or(ProjectTemplate::Greeting);
+    let authors = ProjectAuthors::try_from(matching)
+        .ok()
+        .or(config.get_authors())
+        .or_else(|| git_config_author().map(ProjectAuthors::from));
+    let git_repo = git_requested(matching, &config);
     let (new_called, path, force) = if cmd == "new" {
         match (ProjectPath::try_from(matching), ProjectPath::try_new(false)) {
             (Ok(x), _) => (true, Ok(x), false),
@@ -35,6 +77,7 @@ pub fn main(pair: (&str, ArgMatches)) -> Result<Vec<KojampReport>, Vec<KojampRep
     let tests_n_errors: Vec<KojampReport> = [
         (name.is_valid(), name_error(name.get_inner())),
         (kind.is_valid(), kind_error(From::from(&kind))),
+        (template.is_valid(), template_error(From::from(&template))),
         (path.is_valid(), path_error2(&path.get_absolute_path())),
     ]
     .into_iter()
@@ -45,66 +88,82 @@ pub fn main(pair: (&str, ArgMatches)) -> Result<Vec<KojampReport>, Vec<KojampRep
         return Err(tests_n_errors);
     }
 
-    let project_fields: ProjectFields =
-        ProjectFields::new(name, kind, ProjectAuthors::try_from(matching).ok());
-
-    let mut mut_path = path.get_inner();
-
-    if new_called {
-        create_project_dir(&mut_path).map_err(|_| {
-            vec![dir_file_creation_error(
-                COULD_NOT_CREATE_PROJECT_DIR,
-                &mut_path,
-            )]
-        })?;
+    let project_fields: ProjectFields = ProjectFields::new(
+        name,
+        kind,
+        authors,
+        template,
+        matching.get_flag("lib"),
+    );
+
+    let project_path = path.get_inner();
+    let dry_run = matching.get_flag("dry-run");
+
+    if !new_called {
+        let (empty_dir, not_allowed_content) = match (
+            dir_is_empty(&project_path),
+            dir_contains(&project_path, NOT_ALLOWED_CONTENT),
+        ) {
+            (Ok(x), Ok(y)) => (x, y),
+            _ => return Err(vec![could_not_read_dir_error()]),
+        };
+
+        if !empty_dir && (!force || not_allowed_content) {
+            return Err(vec![non_empty_dir_error()]);
+        }
     }
 
-    let (empty_dir, not_allowed_content) = match (
-        dir_is_empty(&mut_path),
-        dir_contains(&mut_path, NOT_ALLOWED_CONTENT),
-    ) {
-        (Ok(x), Ok(y)) => (x, y),
-        _ => return Err(vec![could_not_read_dir_error()]),
-    };
+    let mut plan = plan_project(&project_path, &project_fields, new_called);
+    let gitignore = plan_gitignore(&project_path);
 
-    if !empty_dir && (!force || not_allowed_content) {
-        return Err(vec![non_empty_dir_error()]);
+    // a dry run fails just like the real one would
+    if let Some(x) = plan.conflict() {
+        return Err(vec![dir_file_conflict_error(
+            COULD_NOT_SCAFFOLD_PROJECT,
+            x,
+        )]);
     }
 
-    create_src_dir(&mut mut_path)
-        .map_err(|x| vec![dir_file_creation_error(COULD_NOT_CREATE_SRC_DIR, &x)])?;
-
-    create_main_source_file(&mut mut_path, &project_fields)
-        .map_err(|x| vec![dir_file_creation_error(COULD_NOT_CREATE_MAIN_SRC_FILE, &x)])?;
-
-    create_toml_file(&mut mut_path, &project_fields)
-        .map_err(|x| vec![dir_file_creation_error(COULD_NOT_CREATE_TOML_FILE, &x)])?;
+    if dry_run {
+        if git_repo {
+            plan.extend(gitignore);
+        }
+        let mut output = env_warnings;
+        output.push(dry_run_report(
+            project_fields.get_name().get_inner(),
+            plan.tree(),
+        ));
+        return Ok(output);
+    }
 
-    let mut output: Vec<KojampReport> = Vec::new();
+    plan.apply().map_err(|x| {
+        vec![dir_file_creation_error(COULD_NOT_SCAFFOLD_PROJECT, &x)]
+    })?;
 
-    if let Some(x) = create_readme_file(&mut mut_path, &project_fields) {
-        output.push(dir_file_creation_warning(COULD_NOT_CREATE_README_FILE, &x));
-    }
+    let mut output: Vec<KojampReport> = env_warnings;
 
     'git_repository: {
         if !git_repo {
             break 'git_repository;
         }
 
-        if initialize_git(&mut_path).is_some() {
+        if initialize_git(&project_path).is_some() {
             output.push(git_init_warning());
             break 'git_repository;
         }
 
-        if let Some(x) = create_git_ignore(&mut mut_path) {
-            output.push(dir_file_creation_warning(COULD_NOT_CREATE_GITIGNORE, &x));
+        if let Err(x) = gitignore.apply() {
+            output.push(dir_file_creation_warning(
+                COULD_NOT_CREATE_GITIGNORE,
+                &x,
+            ));
         }
     }
 
     output.push(success_report(
         project_fields.get_name().get_inner(),
         new_called,
-        &mut_path,
+        &project_path,
     ));
 
     Ok(output)
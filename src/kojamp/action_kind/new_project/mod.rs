@@ -1,6 +1,9 @@
+mod adopt;
 mod files_and_dirs;
 mod main;
 mod reports;
 mod utils;
+mod wizard;
 
+pub use files_and_dirs::content;
 pub use main::main as new_project;
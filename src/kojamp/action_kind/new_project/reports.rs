@@ -1,6 +1,7 @@
 use super::utils::pathbuf_to_str;
 use crate::core::{
     consts::program::PROGRAM_REPO_URL,
+    models::UserConfig,
     reporting::{messages, KojampReport, ReportType},
 };
 use colored::Colorize;
@@ -9,16 +10,27 @@ use std::path::{Path, PathBuf};
 const INVALID_PROJECT_NAME: &str = "Invalid project name";
 const INVALID_PROJECT_KIND: &str = "Invalid project kind";
 const INVALID_PROJECT_PATH: &str = "Invalid project path";
-const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str = "Couldn't get the current directory";
+const INVALID_PROJECT_TEMPLATE: &str = "Invalid project template";
+const NOT_A_TERMINAL: &str = "Couldn't prompt the project name";
+const PROJECT_CREATION_CANCELED: &str = "Project creation canceled";
+const UNDEFINED_ERROR: &str = "Undefined error";
+const COULD_NOT_LOAD_USER_CONFIG: &str = "Couldn't load the user config";
+const IGNORED_ENV_OVERRIDE: &str = "Invalid environment variable";
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
 const COULD_NOT_READ_PROJECT_FOLDER: &str = "Couldn't read project folder";
 const NON_EMPTY_DIR: &str = "Non empty dir";
+const ALREADY_A_KOJAMP_PROJECT: &str = "Already a kojamp project";
+const NO_SOURCES_TO_ADOPT: &str = "No sources to adopt";
+const IGNORED_SOURCES: &str = "Some sources were ignored";
+const NO_MAIN_FOUND: &str = "No main function found";
+const MANY_MAINS_FOUND: &str = "Many main functions found";
+const PROJECT_ADOPTED: &str = "`$$$` project adopted";
 const PROJECT_CREATED: &str = "`$$$` project created";
-pub const COULD_NOT_CREATE_PROJECT_DIR: &str = "Couldn't create the project directory";
-pub const COULD_NOT_CREATE_SRC_DIR: &str = "Couldn't create the `src` directory";
-pub const COULD_NOT_CREATE_MAIN_SRC_FILE: &str = "Couldn't create the main source file";
-pub const COULD_NOT_CREATE_TOML_FILE: &str = "Couldn't create the .toml file";
-pub const COULD_NOT_CREATE_README_FILE: &str = "Couldn't create README file";
-pub const COULD_NOT_INITIALIZE_GIT_REPO: &str = "Couldn't initialize a git repo";
+const PROJECT_PLANNED: &str = "`$$$` project planned (dry run)";
+pub const COULD_NOT_SCAFFOLD_PROJECT: &str = "Couldn't scaffold the project";
+pub const COULD_NOT_INITIALIZE_GIT_REPO: &str =
+    "Couldn't initialize a git repo";
 pub const COULD_NOT_CREATE_GITIGNORE: &str = "Couldn't create .gitignore";
 
 pub fn path_error1() -> KojampReport {
@@ -45,6 +57,46 @@ pub fn kind_error(kind: &str) -> KojampReport {
     )
 }
 
+pub fn template_error(template: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        INVALID_PROJECT_TEMPLATE,
+        messages::invalid_project_template(template),
+    )
+}
+
+pub fn user_config_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_LOAD_USER_CONFIG,
+        messages::user_config_could_not_be_loaded(),
+    )
+}
+
+pub fn not_a_terminal_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NOT_A_TERMINAL,
+        messages::interactive_mode_without_terminal(),
+    )
+}
+
+pub fn creation_canceled_warning() -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        PROJECT_CREATION_CANCELED,
+        messages::project_creation_canceled(),
+    )
+}
+
+pub fn wizard_undefined_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNDEFINED_ERROR,
+        messages::main_app_undefined_error(),
+    )
+}
+
 pub fn path_error2(path: &PathBuf) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
@@ -58,7 +110,16 @@ pub fn dir_file_creation_error(title: &str, path: &Path) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
         title,
-        messages::could_not_create_dir_file(as_str),
+        messages::could_not_apply_scaffold(as_str),
+    )
+}
+
+pub fn dir_file_conflict_error(title: &str, path: &Path) -> KojampReport {
+    let as_str = pathbuf_to_str(path);
+    KojampReport::new(
+        ReportType::Error,
+        title,
+        messages::dir_file_already_exists(as_str),
     )
 }
 
@@ -94,7 +155,19 @@ pub fn git_init_warning() -> KojampReport {
     )
 }
 
-pub fn success_report(project_name: &str, new_called: bool, path: &Path) -> KojampReport {
+pub fn dry_run_report(project_name: &str, tree: String) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        PROJECT_PLANNED.replace("$$$", project_name),
+        messages::planned_project_tree(tree),
+    )
+}
+
+pub fn success_report(
+    project_name: &str,
+    new_called: bool,
+    path: &Path,
+) -> KojampReport {
     KojampReport::new(
         ReportType::Success,
         PROJECT_CREATED.replace("$$$", project_name),
@@ -131,3 +204,63 @@ fn gen_success_message(new_called: bool, path: &Path) -> String {
         PROGRAM_REPO_URL
     )
 }
+
+pub fn already_a_kojamp_project_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        ALREADY_A_KOJAMP_PROJECT,
+        messages::already_a_kojamp_project(),
+    )
+}
+
+pub fn no_sources_to_adopt_error(kind: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NO_SOURCES_TO_ADOPT,
+        messages::no_sources_to_adopt(kind),
+    )
+}
+
+pub fn ignored_sources_warning(count: usize, kind: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        IGNORED_SOURCES,
+        messages::ignored_sources(count, kind),
+    )
+}
+
+pub fn no_main_found_warning(name: &str, kind: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        NO_MAIN_FOUND,
+        messages::no_main_found(name, kind),
+    )
+}
+
+pub fn many_mains_found_warning(mains: &[&str], chosen: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        MANY_MAINS_FOUND,
+        messages::many_mains_found(mains, chosen),
+    )
+}
+
+pub fn adopted_report(
+    project_name: &str,
+    file_count: usize,
+    source_dir: &Path,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        PROJECT_ADOPTED.replace("$$$", project_name),
+        messages::project_adopted(file_count, &source_dir.to_string_lossy()),
+    )
+}
+
+pub fn ignored_env_override(key: &str, value: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        IGNORED_ENV_OVERRIDE,
+        messages::ignored_env_override(&UserConfig::env_var(key), key, value),
+    )
+}
@@ -1,6 +1,12 @@
+use crate::core::{
+    consts::commands::GIT_COMMAND,
+    models::{ProjectAuthor, UserConfig},
+};
+use clap::{parser::ValueSource, ArgMatches};
 use std::{
     fs,
     path::{Path, PathBuf},
+    process::{Command, Stdio},
 };
 
 pub fn pathbuf_to_str(path: &Path) -> &str {
@@ -13,7 +19,10 @@ pub fn dir_is_empty(path: &PathBuf) -> Result<bool, ()> {
     Ok(fs::read_dir(path).map_err(|_| ())?.next().is_none())
 }
 
-pub fn dir_contains<const N: usize>(path: &PathBuf, items: [&str; N]) -> Result<bool, ()> {
+pub fn dir_contains<const N: usize>(
+    path: &PathBuf,
+    items: [&str; N],
+) -> Result<bool, ()> {
     Ok(fs::read_dir(path)
         .map_err(|_| ())?
         .filter_map(|entry| entry.ok())
@@ -25,3 +34,30 @@ pub fn dir_contains<const N: usize>(path: &PathBuf, items: [&str; N]) -> Result<
                 .unwrap_or(false)
         }))
 }
+
+// `--git`/`--no-git` flags first, then the user config (enabled as default)
+pub fn git_requested(matching: &ArgMatches, config: &UserConfig) -> bool {
+    if matching.get_flag("git") {
+        return true;
+    }
+    if matching.value_source("no-git") == Some(ValueSource::CommandLine) {
+        return false;
+    }
+    config.get_git().unwrap_or(true)
+}
+
+fn git_config_value(key: &str) -> Option<String> {
+    let output = Command::new(GIT_COMMAND)
+        .args(["config", key])
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    let value = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    Some(value).filter(|v| output.status.success() && !v.is_empty())
+}
+
+// `user.name <user.email>` from the git config (if there's a name)
+pub fn git_config_author() -> Option<ProjectAuthor> {
+    let name = git_config_value("user.name")?;
+    Some(ProjectAuthor::new(name, git_config_value("user.email")))
+}
@@ -0,0 +1,229 @@
+use super::{
+    reports::*,
+    utils::{git_config_author, git_requested},
+};
+use crate::{
+    core::{
+        contracts::IsValid,
+        models::{ProjectKind, ProjectName, ProjectTemplate, UserConfig},
+        reporting::KojampReport,
+    },
+    kojamp::subcommands,
+    utils::string::StringTransformation,
+};
+use clap::ArgMatches;
+use colored::Colorize;
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    path::Path,
+};
+
+// an empty answer falls back to `default`; `None` means stdin was closed
+fn ask(question: &str, default: Option<&str>) -> Option<String> {
+    print!("{} {}", "?".bright_cyan(), question.bright_white());
+    if let Some(d) = default.filter(|d| !d.is_empty()) {
+        print!(" {}", format!("({})", d).bright_black());
+    }
+    print!(": ");
+    io::stdout().flush().ok()?;
+
+    let mut answer = String::new();
+    match io::stdin().lock().read_line(&mut answer) {
+        Ok(0) | Err(_) => return None,
+        _ => {}
+    }
+
+    let answer = answer.trim();
+    match (answer.is_empty(), default) {
+        (true, Some(d)) => Some(d.to_string()),
+        _ => Some(answer.to_string()),
+    }
+}
+
+fn ask_yes_no(question: &str, default: bool) -> Option<bool> {
+    loop {
+        let answer = ask(question, Some(if default { "yes" } else { "no" }))?;
+        match answer.to_lowercase().as_ref() {
+            "y" | "yes" => return Some(true),
+            "n" | "no" => return Some(false),
+            _ => println!("  {} answer with `yes` or `no`", "x".bright_red()),
+        }
+    }
+}
+
+fn ask_name(default: Option<String>) -> Option<String> {
+    let mut suggestion = default;
+
+    loop {
+        let answer = ask("Project name", suggestion.as_deref())?;
+        if ProjectName::from(answer.as_str()).is_valid() {
+            return Some(answer);
+        }
+
+        println!(
+            "  {} `{}` isn't a valid project name {}",
+            "x".bright_red(),
+            answer.bright_red(),
+            "(camel cased, starting with A-Z)".bright_black()
+        );
+
+        let fixed = answer.as_str().to_valid_camel_case();
+        suggestion = if ProjectName::from(fixed.as_str()).is_valid() {
+            println!("  press enter to use `{}` instead", fixed.bright_green());
+            Some(fixed)
+        } else {
+            None
+        };
+    }
+}
+
+fn ask_kind(default: &str) -> Option<String> {
+    loop {
+        let answer = ask("Project kind (java/kotlin)", Some(default))?;
+        if ProjectKind::from(answer.as_str()).is_valid() {
+            return Some(answer.to_lowercase());
+        }
+        println!(
+            "  {} `{}` isn't a project kind",
+            "x".bright_red(),
+            answer.bright_red()
+        );
+    }
+}
+
+fn ask_path(default: &str) -> Option<String> {
+    loop {
+        let answer = ask("Project path", Some(default))?;
+        if !answer.is_empty() && !Path::new(&answer).exists() {
+            return Some(answer);
+        }
+        println!(
+            "  {} `{}` already exists (or is empty)",
+            "x".bright_red(),
+            answer.bright_red()
+        );
+    }
+}
+
+fn ask_template(default: &str) -> Option<String> {
+    loop {
+        let answer =
+            ask("Main file template (greeting/minimal)", Some(default))?;
+        if ProjectTemplate::from(answer.as_str()).is_valid() {
+            return Some(answer.to_lowercase());
+        }
+        println!(
+            "  {} `{}` isn't a template",
+            "x".bright_red(),
+            answer.bright_red()
+        );
+    }
+}
+
+fn print_summary(fields: &[(&str, &str)]) {
+    println!();
+    println!("{}", "Project summary:".bright_white());
+    for (field, value) in fields {
+        let value = if value.is_empty() { "-" } else { value };
+        println!(
+            "... {} {:<9} {}",
+            "*".bright_green(),
+            field,
+            value.bright_cyan()
+        );
+    }
+    println!();
+}
+
+// returns the matches equivalent to the answers (`None` when canceled)
+pub fn run(
+    matching: &ArgMatches,
+    config: &UserConfig,
+) -> Result<Option<ArgMatches>, Vec<KojampReport>> {
+    // with no terminal to prompt at, the given (or config) values are used
+    // as is, so only the name can be missing
+    if !io::stdin().is_terminal() {
+        return match matching.get_one::<String>("name") {
+            Some(_) => Ok(Some(matching.clone())),
+            None => Err(vec![not_a_terminal_error()]),
+        };
+    }
+
+    // args first, then the user config values
+    let given =
+        |id: &str| matching.get_one::<String>(id).cloned().or(config.get(id));
+
+    let Some(name) = ask_name(given("name")) else {
+        return Ok(None);
+    };
+    let Some(kind) = ask_kind(&given("kind").unwrap_or("java".into())) else {
+        return Ok(None);
+    };
+    let Some(path) =
+        ask_path(&given("path").unwrap_or(name.as_str().to_kebab_case()))
+    else {
+        return Ok(None);
+    };
+    let default_authors =
+        given("authors").or_else(|| git_config_author().map(|a| a.to_string()));
+    let Some(authors) =
+        ask("Authors (Name <email>, ...)", default_authors.as_deref())
+    else {
+        return Ok(None);
+    };
+    let Some(template) =
+        ask_template(&given("template").unwrap_or("greeting".into()))
+    else {
+        return Ok(None);
+    };
+    let Some(library) =
+        ask_yes_no("Library project (no main class)", matching.get_flag("lib"))
+    else {
+        return Ok(None);
+    };
+    let Some(git) =
+        ask_yes_no("Initialize a git repo", git_requested(matching, config))
+    else {
+        return Ok(None);
+    };
+
+    print_summary(&[
+        ("name", &name),
+        ("kind", &kind),
+        ("path", &path),
+        ("authors", &authors),
+        ("template", &template),
+        ("library", if library { "yes" } else { "no" }),
+        ("git", if git { "yes" } else { "no" }),
+    ]);
+
+    if !ask_yes_no("Create the project", true).unwrap_or(false) {
+        return Ok(None);
+    }
+
+    let mut args = vec![
+        "new".to_string(),
+        name,
+        "--kind".into(),
+        kind,
+        "--path".into(),
+        path,
+        "--template".into(),
+        template,
+    ];
+    if !authors.is_empty() {
+        args.extend(["--authors".into(), authors]);
+    }
+    args.push(if git { "--git" } else { "--no-git" }.into());
+    if library {
+        args.push("--lib".into());
+    }
+    if matching.get_flag("dry-run") {
+        args.push("--dry-run".into());
+    }
+
+    subcommands::new::cmd()
+        .try_get_matches_from(args)
+        .map(Some)
+        .map_err(|_| vec![wizard_undefined_error()])
+}
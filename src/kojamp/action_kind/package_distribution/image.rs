@@ -0,0 +1,134 @@
+use super::{reports::*, utils::*};
+use crate::core::reporting::KojampReport;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+pub struct RuntimeImage {
+    pub size: u64,
+    pub modules: Vec<String>,
+}
+
+// the selected JDK is the `JAVA_HOME` one (otherwise the `PATH` tools)
+fn jdk_tool(name: &str) -> PathBuf {
+    let file = format!("{}{}", name, env::consts::EXE_SUFFIX);
+    match env::var_os("JAVA_HOME").filter(|h| !h.is_empty()) {
+        Some(home) => PathBuf::from(home).join("bin").join(file),
+        None => PathBuf::from(file),
+    }
+}
+
+fn run_tool(tool: &str, command: &mut Command) -> Result<String, KojampReport> {
+    let result = command.output().map_err(|_| jdk_tool_not_found(tool))?;
+    let stdout = String::from_utf8_lossy(&result.stdout).to_string();
+    // both tools print their errors to stdout too
+    match result.status.success() {
+        true => Ok(stdout),
+        false => Err(jdk_tool_failed(
+            tool,
+            format!("{}{}", stdout, String::from_utf8_lossy(&result.stderr))
+                .trim(),
+        )),
+    }
+}
+
+// jars at the class path don't declare their modules, so `jdeps` figures
+// them out from the bytecode (`java.base` is always there)
+fn required_modules(jars: &[PathBuf]) -> Result<Vec<String>, KojampReport> {
+    let mut command = Command::new(jdk_tool("jdeps"));
+    command.args([
+        "--print-module-deps",
+        "--ignore-missing-deps",
+        "--multi-release",
+        "base",
+    ]);
+    command.args(jars);
+
+    // warnings (split packages...) come before the module list
+    let printed = run_tool("jdeps", &mut command)?;
+    let mut modules: Vec<String> = printed
+        .lines()
+        .rfind(|l| !l.trim().is_empty())
+        .unwrap_or_default()
+        .split(',')
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty())
+        .collect();
+    if !modules.iter().any(|m| m == "java.base") {
+        modules.insert(0, String::from("java.base"));
+    }
+    Ok(modules)
+}
+
+fn link_runtime(modules: &[String], output: &Path) -> Result<(), KojampReport> {
+    let mut command = Command::new(jdk_tool("jlink"));
+    let jmods = env::var_os("JAVA_HOME")
+        .filter(|h| !h.is_empty())
+        .map(|h| PathBuf::from(h).join("jmods"))
+        .filter(|j| j.is_dir());
+    if let Some(j) = jmods {
+        command.arg("--module-path").arg(j);
+    }
+    command
+        .arg("--add-modules")
+        .arg(modules.join(","))
+        .arg("--output")
+        .arg(output)
+        .args([
+            "--strip-debug",
+            "--no-header-files",
+            "--no-man-pages",
+            "--compress=2",
+        ]);
+
+    run_tool("jlink", &mut command).map(|_| ())
+}
+
+// the distribution content (but its launchers) plus a trimmed runtime at
+// `runtime`, started by launchers that don't need an installed java
+pub fn create(
+    staging: &Path,
+    image_dir: &Path,
+    launchers: &[(String, String)],
+    jars: &[String],
+    jvm_options: &[String],
+) -> Result<RuntimeImage, KojampReport> {
+    let _ = fs::remove_dir_all(image_dir);
+    let files =
+        collect_files(staging).map_err(|_| could_not_write_file(staging))?;
+    for (name, path) in files.iter() {
+        if name.starts_with(&format!("{}/", BIN_DIR)) {
+            continue;
+        }
+        let target = image_dir.join(name);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|_| could_not_write_file(parent))?;
+        }
+        fs::copy(path, &target).map_err(|_| could_not_write_file(&target))?;
+    }
+
+    let jar_files: Vec<PathBuf> =
+        jars.iter().map(|j| image_dir.join(j)).collect();
+    let modules = required_modules(&jar_files)?;
+    link_runtime(&modules, &image_dir.join(RUNTIME_DIR))?;
+    write_launchers(
+        &image_dir.join(BIN_DIR),
+        launchers,
+        jars,
+        jvm_options,
+        true,
+    )
+    .map_err(|f| could_not_write_file(&f))?;
+
+    let size = collect_files(image_dir)
+        .map_err(|_| could_not_write_file(image_dir))?
+        .iter()
+        .filter_map(|(_, p)| fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum();
+
+    Ok(RuntimeImage { size, modules })
+}
@@ -0,0 +1,215 @@
+use super::{image, reports::*, utils::*};
+use crate::{
+    core::{
+        models::{ProjectGraph, ProjectWorkspace},
+        reporting::KojampReport,
+    },
+    kojamp::{
+        action::{
+            build_project,
+            build_project::utils::{module_names, output_entry, pack_classes},
+        },
+        subcommands,
+    },
+    utils::path::display,
+};
+use clap::ArgMatches;
+use std::{fs, path::PathBuf};
+
+const DIST_DIR: &str = "dist";
+const IMAGE_DIR: &str = "image";
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let package = matching.get_one::<String>("package");
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+
+    // a single project (workspaces need the member to pack)
+    let (workspace, roots) = match (ProjectWorkspace::load(&curdir), package) {
+        (Ok(Some(ws)), Some(_)) => {
+            let roots: Vec<PathBuf> =
+                ws.get_members().iter().map(|m| ws.member_root(m)).collect();
+            (Some(ws), roots)
+        }
+        (Ok(Some(ws)), None) => {
+            return Err(vec![member_required(ws.get_members())])
+        }
+        (Err(e), _) => return Err(vec![manifest_error(e)]),
+        (_, Some(_)) => return Err(vec![not_a_workspace()]),
+        _ => (None, vec![curdir.clone()]),
+    };
+
+    let graph =
+        ProjectGraph::resolve(&roots).map_err(|e| vec![graph_error(e)])?;
+    let index = match (&workspace, package) {
+        (Some(ws), Some(p)) => ws
+            .find_member(&graph, p)
+            .ok_or(vec![unknown_member(p, ws.get_members())])?,
+        _ => graph.find(&curdir).ok_or(vec![dist_undefined_error()])?,
+    };
+
+    let manifest = graph.get_node(index).get_manifest();
+    if manifest.is_library() {
+        return Err(vec![library_cant_be_distributed(manifest.get_name())]);
+    }
+
+    // every project is packed into a single jar, which many modules can't be
+    let many_modules = graph
+        .dependencies_of(index)
+        .into_iter()
+        .chain([index])
+        .map(|i| graph.get_node(i))
+        .find(|n| {
+            let source_dir =
+                n.get_root().join(n.get_manifest().get_source_dir());
+            module_names(&source_dir).len() > 1
+        });
+    if let Some(node) = many_modules {
+        let name = node.get_manifest().get_name();
+        return Err(vec![project_with_many_modules(name)]);
+    }
+
+    // the distribution is always made from fresh outputs
+    let build_args = match package {
+        Some(p) => vec!["build", "-p", p],
+        None => vec!["build"],
+    };
+    let build_matching = subcommands::build::cmd()
+        .try_get_matches_from(build_args)
+        .map_err(|_| vec![dist_undefined_error()])?;
+    let mut output = build_project(build_matching)?;
+
+    let out_dir = |index: usize| graph.out_dir(index, workspace.as_ref());
+
+    let dist_name =
+        format!("{}-{}", manifest.get_artifact(), manifest.get_version());
+    let dist_dir = out_dir(index).join(DIST_DIR);
+    let staging = dist_dir.join(&dist_name);
+    let _ = fs::remove_dir_all(&staging);
+    for dir in [staging.join(BIN_DIR), staging.join(LIB_DIR)] {
+        fs::create_dir_all(&dir)
+            .map_err(|_| vec![could_not_write_file(&dir)])?;
+    }
+
+    let bins: Vec<(String, String)> = manifest
+        .get_bins()
+        .iter()
+        .map(|b| (b.get_name().to_string(), b.get_main_class().to_string()))
+        .collect();
+    let launchers = match bins.is_empty() {
+        true => vec![(manifest.get_artifact(), manifest.get_main_class())],
+        false => bins,
+    };
+
+    // the project jar (java classes are packed, kotlin builds are jars)
+    let app_jar = manifest.get_library_jar();
+    let built =
+        output_entry(&out_dir(index), manifest.get_name(), manifest.get_kind());
+    match manifest.get_kind() {
+        "java" => {
+            let main_class = launchers
+                .first()
+                .map(|(_, m)| m.as_str())
+                .filter(|_| launchers.len() == 1);
+            pack_classes(&built, &staging.join(&app_jar), main_class)
+                .map_err(|_| vec![could_not_pack_jar(&app_jar)])?;
+        }
+        _ => {
+            fs::copy(&built, staging.join(&app_jar))
+                .map_err(|_| vec![could_not_pack_jar(&app_jar)])?;
+        }
+    }
+
+    // path dependencies are packed by their own coordinate, while jars keep
+    // their file name
+    let dependencies = graph.dependencies_of(index);
+    let (classpath, missing) = graph.classpath(index, |d| {
+        let dep = graph.get_node(d).get_manifest();
+        output_entry(&out_dir(d), dep.get_name(), dep.get_kind())
+    });
+    output.extend(missing.iter().map(|m| missing_dependency(m)));
+
+    let mut jars: Vec<String> = vec![app_jar];
+    for (position, entry) in classpath.iter().enumerate() {
+        let project = dependencies
+            .get(position)
+            .map(|d| graph.get_node(*d).get_manifest());
+        let file_name = match project {
+            Some(dep) => dep.get_library_jar(),
+            None => entry
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+        };
+        let lib_file = format!("{}/{}", LIB_DIR, file_name);
+        if jars.contains(&lib_file) {
+            continue;
+        }
+
+        let target = staging.join(LIB_DIR).join(&file_name);
+        let copied = match entry.is_dir() {
+            true => pack_classes(entry, &target, None),
+            false => fs::copy(entry, &target).map(|_| ()).map_err(|_| ()),
+        };
+        match copied {
+            Ok(()) => jars.push(lib_file),
+            Err(()) => {
+                output.push(dependency_left_out(&display(entry, &curdir)))
+            }
+        }
+    }
+
+    let jvm_options = manifest.get_jvm_options();
+    write_launchers(
+        &staging.join(BIN_DIR),
+        &launchers,
+        &jars,
+        jvm_options,
+        false,
+    )
+    .map_err(|f| vec![could_not_write_file(&f)])?;
+
+    let files = collect_files(&staging)
+        .map_err(|_| vec![could_not_write_file(&staging)])?;
+    let tar_gz = dist_dir.join(format!("{}.tar.gz", dist_name));
+    write_tar_gz(&tar_gz, &dist_name, &files)
+        .map_err(|_| vec![could_not_write_file(&tar_gz)])?;
+    let zip = dist_dir.join(format!("{}.zip", dist_name));
+    write_zip(&zip, &dist_name, &files)
+        .map_err(|_| vec![could_not_write_file(&zip)])?;
+
+    let launcher_names: Vec<&str> =
+        launchers.iter().map(|(n, _)| n.as_str()).collect();
+    output.push(success_report(
+        manifest.get_name(),
+        &[display(&tar_gz, &curdir), display(&zip, &curdir)],
+        &launcher_names,
+    ));
+
+    if matching.get_flag("jlink") {
+        let image_dir = out_dir(index).join(IMAGE_DIR);
+        match image::create(
+            &staging,
+            &image_dir,
+            &launchers,
+            &jars,
+            jvm_options,
+        ) {
+            Ok(image) => output.push(image_report(
+                manifest.get_name(),
+                &display(&image_dir, &curdir),
+                &human_size(image.size),
+                &image.modules,
+                &launcher_names,
+            )),
+            Err(report) => {
+                output.push(report);
+                return Err(output);
+            }
+        }
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,6 @@
+mod image;
+mod main;
+mod reports;
+mod utils;
+
+pub use main::main as package_distribution;
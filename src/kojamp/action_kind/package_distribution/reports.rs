@@ -0,0 +1,200 @@
+use crate::core::{
+    models::{GraphError, ManifestError},
+    reporting::{messages, KojampReport, ReportType},
+};
+use colored::Colorize;
+use std::path::Path;
+
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
+const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
+const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
+const NOT_A_WORKSPACE: &str = "Not a workspace";
+const MEMBER_REQUIRED: &str = "No workspace member specified";
+const UNKNOWN_MEMBER: &str = "Unknown workspace member";
+const INVALID_PROJECT_DEPENDENCY: &str = "Invalid project at `$$$`";
+const DEPENDENCY_CYCLE: &str = "Dependency cycle";
+const MISSING_DEPENDENCY: &str = "Missing dependency";
+const LIBRARY_CANT_BE_DISTRIBUTED: &str = "Libraries can't be distributed";
+const PROJECT_WITH_MANY_MODULES: &str =
+    "Projects with many modules can't be distributed";
+const COULD_NOT_PACK_JAR: &str = "Couldn't pack the project jar";
+const COULD_NOT_WRITE_FILE: &str = "Couldn't write `$$$`";
+const DEPENDENCY_LEFT_OUT: &str = "Dependency left out of the distribution";
+const DISTRIBUTION_CREATED: &str = "The `$$$` distribution was created";
+const JDK_TOOL_NOT_FOUND: &str = "Couldn't run `$$$`";
+const JDK_TOOL_FAILED: &str = "`$$$` failed";
+const IMAGE_CREATED: &str = "The `$$$` runtime image was created";
+
+pub fn could_not_get_curdir() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_GET_THE_CURRENT_DIRECTORY,
+        messages::invalid_cur_dir(),
+    )
+}
+
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => KojampReport::new(
+            ReportType::Error,
+            PROBABLY_A_NON_KOJAMP_PROJECT,
+            messages::kojamp_toml_not_found(),
+        ),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn dist_undefined_error() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        "Undefined error",
+        messages::main_app_undefined_error(),
+    )
+}
+
+pub fn not_a_workspace() -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NOT_A_WORKSPACE,
+        messages::not_a_workspace(),
+    )
+}
+
+pub fn member_required(members: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        MEMBER_REQUIRED,
+        messages::workspace_member_required("dist", members),
+    )
+}
+
+pub fn unknown_member(name: &str, members: &[String]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNKNOWN_MEMBER,
+        messages::unknown_workspace_member(name, members),
+    )
+}
+
+pub fn graph_error(error: GraphError) -> KojampReport {
+    match error {
+        GraphError::Manifest(path, e) => KojampReport::new(
+            ReportType::Error,
+            INVALID_PROJECT_DEPENDENCY.replace("$$$", &path.to_string_lossy()),
+            match e {
+                ManifestError::Missing => messages::kojamp_toml_not_found(),
+                ManifestError::Unreadable => {
+                    messages::toml_file_could_not_be_read()
+                }
+                ManifestError::Invalid => messages::invalid_toml_file(),
+            },
+        ),
+        GraphError::Cycle(cycle) => KojampReport::new(
+            ReportType::Error,
+            DEPENDENCY_CYCLE,
+            messages::dependency_cycle(&cycle),
+        ),
+    }
+}
+
+pub fn missing_dependency(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        MISSING_DEPENDENCY,
+        messages::missing_dependency(name),
+    )
+}
+
+pub fn library_cant_be_distributed(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        LIBRARY_CANT_BE_DISTRIBUTED,
+        messages::library_cant_be_distributed(name),
+    )
+}
+
+pub fn project_with_many_modules(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        PROJECT_WITH_MANY_MODULES,
+        messages::project_with_many_modules(name),
+    )
+}
+
+pub fn could_not_pack_jar(jar: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_PACK_JAR,
+        messages::could_not_pack_jar(jar),
+    )
+}
+
+pub fn could_not_write_file(file: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        COULD_NOT_WRITE_FILE.replace("$$$", &file.to_string_lossy()),
+        messages::could_not_write_to_repository(&file.to_string_lossy()),
+    )
+}
+
+pub fn dependency_left_out(entry: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        DEPENDENCY_LEFT_OUT,
+        messages::dependency_left_out_of_dist(entry),
+    )
+}
+
+pub fn success_report(
+    name: &str,
+    archives: &[String],
+    launchers: &[&str],
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        DISTRIBUTION_CREATED
+            .replace("$$$", &format!("{}", name.bright_green())),
+        messages::distribution_created(archives, launchers),
+    )
+}
+
+pub fn jdk_tool_not_found(tool: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        JDK_TOOL_NOT_FOUND.replace("$$$", tool),
+        messages::jdk_tool_not_found(tool),
+    )
+}
+
+pub fn jdk_tool_failed(tool: &str, printed: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        JDK_TOOL_FAILED.replace("$$$", tool),
+        messages::jdk_tool_failed(printed),
+    )
+}
+
+pub fn image_report(
+    name: &str,
+    image_dir: &str,
+    size: &str,
+    modules: &[String],
+    launchers: &[&str],
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Success,
+        IMAGE_CREATED.replace("$$$", &format!("{}", name.bright_green())),
+        messages::runtime_image_created(image_dir, size, modules, launchers),
+    )
+}
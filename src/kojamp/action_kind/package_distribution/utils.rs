@@ -0,0 +1,251 @@
+use flate2::{write::GzEncoder, Compression};
+use std::{
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+};
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+pub const BIN_DIR: &str = "bin";
+pub const LIB_DIR: &str = "lib";
+pub const RUNTIME_DIR: &str = "runtime";
+
+// the out dir classes packed by the `jar` tool, leaving out the jars and
+// tool dirs previously placed there
+pub fn human_size(bytes: u64) -> String {
+    let units = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} {}", bytes, units[0]),
+        _ => format!("{:.1} {}", size, units[unit]),
+    }
+}
+
+// single quoted (the only char to escape is the quote itself)
+fn sh_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn bat_quote(value: &str) -> String {
+    match value.contains([' ', '&', '|', '<', '>', '^']) {
+        true => format!("\"{}\"", value),
+        false => value.to_string(),
+    }
+}
+
+// `JAVA_HOME` is preferred over the `PATH` java (unless the runtime is
+// bundled), and `JAVA_OPTS` comes before the configured options (so these
+// ones win)
+pub fn posix_launcher(
+    main_class: &str,
+    jars: &[String],
+    jvm_options: &[String],
+    bundled: bool,
+) -> String {
+    let classpath = jars
+        .iter()
+        .map(|j| format!("$APP_HOME/{}", j))
+        .collect::<Vec<_>>()
+        .join(":");
+    let options: String = jvm_options
+        .iter()
+        .map(|o| format!(" {}", sh_quote(o)))
+        .collect();
+
+    let java = match bundled {
+        true => format!("JAVA=\"$APP_HOME/{}/bin/java\"", RUNTIME_DIR),
+        false => String::from(
+            "\
+if [ -n \"$JAVA_HOME\" ]; then
+    JAVA=\"$JAVA_HOME/bin/java\"
+else
+    JAVA=java
+fi",
+        ),
+    };
+
+    format!(
+        r#"#!/bin/sh
+# generated by kojamp
+
+APP_HOME=$(cd "$(dirname "$0")/.." && pwd -P)
+
+{}
+
+exec "$JAVA" $JAVA_OPTS{} -cp "{}" {} "$@"
+"#,
+        java, options, classpath, main_class
+    )
+}
+
+pub fn windows_launcher(
+    main_class: &str,
+    jars: &[String],
+    jvm_options: &[String],
+    bundled: bool,
+) -> String {
+    let classpath = jars
+        .iter()
+        .map(|j| format!("%APP_HOME%\\{}", j.replace('/', "\\")))
+        .collect::<Vec<_>>()
+        .join(";");
+    let options: String = jvm_options
+        .iter()
+        .map(|o| format!(" {}", bat_quote(o)))
+        .collect();
+
+    [
+        "@echo off",
+        "rem generated by kojamp",
+        "",
+        "setlocal",
+        "set APP_HOME=%~dp0..",
+        "",
+        &match bundled {
+            true => format!("set JAVA=%APP_HOME%\\{}\\bin\\java", RUNTIME_DIR),
+            false => String::from(
+                "set JAVA=java\r\nif defined JAVA_HOME set JAVA=%JAVA_HOME%\\bin\\java",
+            ),
+        },
+        "",
+        &format!(
+            "\"%JAVA%\" %JAVA_OPTS%{} -cp \"{}\" {} %*",
+            options, classpath, main_class
+        ),
+        "",
+    ]
+    .join("\r\n")
+}
+
+// a POSIX and a windows launcher for each `(name, main class)` pair
+pub fn write_launchers(
+    bin_dir: &Path,
+    launchers: &[(String, String)],
+    jars: &[String],
+    jvm_options: &[String],
+    bundled: bool,
+) -> Result<(), PathBuf> {
+    fs::create_dir_all(bin_dir).map_err(|_| bin_dir.to_path_buf())?;
+    for (name, main_class) in launchers {
+        let posix = bin_dir.join(name);
+        fs::write(
+            &posix,
+            posix_launcher(main_class, jars, jvm_options, bundled),
+        )
+        .map_err(|_| posix.clone())?;
+        set_executable(&posix);
+
+        let windows = bin_dir.join(format!("{}.bat", name));
+        fs::write(
+            &windows,
+            windows_launcher(main_class, jars, jvm_options, bundled),
+        )
+        .map_err(|_| windows.clone())?;
+    }
+    Ok(())
+}
+
+// the unpacked distribution (at `out/dist`) can be launched too
+#[cfg(unix)]
+pub fn set_executable(file: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+    let _ = fs::set_permissions(file, fs::Permissions::from_mode(0o755));
+}
+
+#[cfg(not(unix))]
+pub fn set_executable(_file: &Path) {}
+
+// every file under the dir as `(archive path, file path)` pairs, sorted
+pub fn collect_files(dir: &Path) -> io::Result<Vec<(String, PathBuf)>> {
+    fn walk(
+        dir: &Path,
+        prefix: &str,
+        output: &mut Vec<(String, PathBuf)>,
+    ) -> io::Result<()> {
+        for entry in dir.read_dir()? {
+            let entry = entry?;
+            let name =
+                format!("{}{}", prefix, entry.file_name().to_string_lossy());
+            match entry.path().is_dir() {
+                true => walk(&entry.path(), &format!("{}/", name), output)?,
+                false => output.push((name, entry.path())),
+            }
+        }
+        Ok(())
+    }
+
+    let mut output = Vec::new();
+    walk(dir, "", &mut output)?;
+    output.sort();
+    Ok(output)
+}
+
+// launchers (the `bin` files but the `.bat` ones) are executable
+fn is_executable(name: &str) -> bool {
+    name.starts_with(&format!("{}/", BIN_DIR)) && !name.ends_with(".bat")
+}
+
+pub fn write_tar_gz(
+    archive: &Path,
+    prefix: &str,
+    files: &[(String, PathBuf)],
+) -> io::Result<()> {
+    let encoder =
+        GzEncoder::new(File::create(archive)?, Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+
+    for (name, path) in files {
+        let content = fs::read(path)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(match is_executable(name) {
+            true => 0o755,
+            false => 0o644,
+        });
+        header.set_mtime(
+            fs::metadata(path)?
+                .modified()?
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        );
+        builder.append_data(
+            &mut header,
+            format!("{}/{}", prefix, name),
+            content.as_slice(),
+        )?;
+    }
+
+    builder.into_inner()?.finish()?;
+    Ok(())
+}
+
+pub fn write_zip(
+    archive: &Path,
+    prefix: &str,
+    files: &[(String, PathBuf)],
+) -> io::Result<()> {
+    let mut writer = ZipWriter::new(File::create(archive)?);
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated);
+
+    for (name, path) in files {
+        let mode = match is_executable(name) {
+            true => 0o755,
+            false => 0o644,
+        };
+        writer.start_file(
+            format!("{}/{}", prefix, name),
+            options.unix_permissions(mode),
+        )?;
+        io::copy(&mut File::open(path)?, &mut writer)?;
+    }
+
+    writer.finish()?;
+    Ok(())
+}
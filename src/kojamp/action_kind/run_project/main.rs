@@ -1,12 +1,40 @@
-use super::{reports::*, utils::*};
-use crate::core::reporting::KojampReport;
+use super::{mains, reports::*, utils::*};
+use crate::{
+    core::{
+        models::{ProjectBin, ProjectGraph, ProjectWorkspace},
+        reporting::KojampReport,
+    },
+    kojamp::action::build_project::utils::{item_is_here, output_entry},
+};
+use clap::ArgMatches;
 use colored::Colorize;
-use std::fs;
+use std::path::PathBuf;
 
 const KOJAMP_TOML: &str = "Kojamp.toml";
 
-pub fn main() -> Result<Vec<KojampReport>, Vec<KojampReport>> {
-    let curdir = std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
+// the `[[bin]]` entry main class (`None` when there are no entries)
+pub fn select_bin(
+    bins: &[ProjectBin],
+    requested: Option<&String>,
+) -> Result<Option<String>, KojampReport> {
+    match (bins, requested) {
+        ([], None) => Ok(None),
+        ([], Some(r)) => Err(no_bin_entries(r)),
+        ([bin], None) => Ok(Some(bin.get_main_class().to_string())),
+        (_, None) => Err(bin_required(bins)),
+        (_, Some(r)) => bins
+            .iter()
+            .find(|bin| bin.get_name() == r)
+            .map(|bin| Some(bin.get_main_class().to_string()))
+            .ok_or(unknown_bin(r, bins)),
+    }
+}
+
+pub fn main(
+    matching: ArgMatches,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let curdir =
+        std::env::current_dir().map_err(|_| vec![could_not_get_curdir()])?;
 
     match item_is_here(curdir.as_path(), KOJAMP_TOML) {
         Ok(false) => return Err(vec![kojamp_toml_is_missing()]),
@@ -14,32 +42,89 @@ pub fn main() -> Result<Vec<KojampReport>, Vec<KojampReport>> {
         _ => {}
     }
 
-    let toml_content = fs::read_to_string(curdir.join(KOJAMP_TOML))
-        .map_err(|_| vec![could_not_read_toml_file()])?;
+    let package = matching.get_one::<String>("package");
+
+    // a single workspace member can be run
+    if let (Ok(Some(ws)), None) = (ProjectWorkspace::load(&curdir), package) {
+        return Err(vec![member_required(ws.get_members())]);
+    }
+
+    let (workspace, graph, targets) =
+        ProjectWorkspace::targets(&curdir, package.map(String::as_str))
+            .map_err(|e| vec![target_error(e)])?;
+    let index = targets[0];
 
-    let mut errors: Vec<KojampReport> = Vec::new();
+    if matching.get_flag("list-mains") {
+        let node = graph.get_node(index);
+        return mains::list(node.get_root(), node.get_manifest());
+    }
+
+    run(&graph, index, workspace.as_ref(), matching.get_one("bin"))
+}
 
-    let name = get_project_name_from_toml(&toml_content)
-        .map_err(|_| errors.push(could_not_get_project_name_from_toml()));
-    let kind = get_project_kind_from_toml(&toml_content)
-        .map_err(|_| errors.push(could_not_get_project_kind_from_toml()));
+// the project output comes first at the classpath, then its dependencies
+// (path ones already built at their `out` dir) and jars
+fn run(
+    graph: &ProjectGraph,
+    index: usize,
+    workspace: Option<&ProjectWorkspace>,
+    bin: Option<&String>,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let entry = |index: usize| {
+        let manifest = graph.get_node(index).get_manifest();
+        output_entry(
+            &graph.out_dir(index, workspace),
+            manifest.get_name(),
+            manifest.get_kind(),
+        )
+    };
 
-    if !errors.is_empty() {
-        return Err(errors);
+    let manifest = graph.get_node(index).get_manifest();
+    if manifest.is_library() {
+        return Err(vec![library_cant_be_run(manifest.get_name())]);
     }
 
-    let (name, kind) = (name.unwrap(), kind.unwrap());
-    let output_path = try_generate_output_path(name.as_str(), kind.as_str()).ok_or(vec![
-        could_not_generate_output_file_path(name.as_str(), kind.as_str()),
-    ])?;
+    let main_class = select_bin(manifest.get_bins(), bin)
+        .map_err(|e| vec![e])?
+        .unwrap_or(manifest.get_main_class());
+    let own_entry = entry(index);
 
-    let output_exists = output_exists(curdir.as_path(), output_path.as_path());
-    if !output_exists.0 {
-        return Err(vec![output_file_doesnt_exists(output_exists.1.as_path())]);
+    // modular java projects are run by `--module <module>/<main class>`
+    let source_dir = graph
+        .get_node(index)
+        .get_root()
+        .join(manifest.get_source_dir());
+    let module = main_module(&own_entry, &source_dir, &main_class)
+        .filter(|_| manifest.get_kind() == "java");
+    let expected = match (manifest.get_kind(), &module) {
+        ("java", Some((_, classes))) => classes
+            .join(main_class.replace('.', "/"))
+            .with_extension("class"),
+        ("java", None) => own_entry
+            .join(main_class.replace('.', "/"))
+            .with_extension("class"),
+        _ => own_entry.clone(),
+    };
+
+    if !expected.is_file() {
+        return Err(vec![output_file_doesnt_exists(&expected)]);
     }
 
-    println!("Running `{}` project:\x1b[0m\n", name.bright_green());
-    run_output(kind.as_str(), output_exists.1.as_path());
+    let (dependencies, _) = graph.classpath(index, entry);
+    let classpath: Vec<PathBuf> =
+        [own_entry].into_iter().chain(dependencies).collect();
+
+    println!(
+        "Running `{}` project:\n",
+        manifest.get_name().bright_green()
+    );
+    let jvm_options = manifest.get_jvm_options();
+    match module {
+        Some((module, _)) => {
+            run_module(&classpath, &module, &main_class, jvm_options)
+        }
+        None => run_with_classpath(&classpath, &main_class, jvm_options),
+    }
 
     Ok(vec![])
 }
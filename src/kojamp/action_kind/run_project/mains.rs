@@ -0,0 +1,70 @@
+use super::reports::*;
+use crate::{
+    core::{models::ProjectManifest, reporting::KojampReport},
+    utils::source,
+};
+use colored::Colorize;
+use std::path::Path;
+
+// every main entry point at the sources (the configured ones are marked)
+pub fn list(
+    root: &Path,
+    manifest: &ProjectManifest,
+) -> Result<Vec<KojampReport>, Vec<KojampReport>> {
+    let source_dir = manifest.get_source_dir();
+    let sources =
+        source::collect_sources(manifest.get_kind(), &root.join(source_dir))
+            .map_err(|p| vec![unreadable_source(source_dir, &p)])?;
+    let mains = source::find_mains(manifest.get_kind(), &sources);
+
+    if mains.is_empty() {
+        return Err(vec![no_mains_found(source_dir)]);
+    }
+
+    let main_class = manifest.get_main_class();
+    let mut configured = false;
+    let width = mains.iter().map(|(_, m)| m.class.len()).max().unwrap_or(0);
+
+    println!(
+        "Main entries at `{}` project:\n",
+        manifest.get_name().bright_green()
+    );
+    for (file, main) in &mains {
+        let location = format!(
+            "{}:{}",
+            file.strip_prefix(root).unwrap_or(file).to_string_lossy(),
+            main.line
+        );
+        let bin = manifest
+            .get_bins()
+            .iter()
+            .find(|b| b.get_main_class() == main.class);
+        let mark = match bin {
+            Some(b) => format!("(bin `{}`)", b.get_name()),
+            None if manifest.get_bins().is_empty()
+                && !manifest.is_library()
+                && main.class == main_class =>
+            {
+                configured = true;
+                String::from("(main-class)")
+            }
+            None => String::new(),
+        };
+        println!(
+            "  {} {} {}",
+            format!("{:width$}", main.class, width = width).bright_cyan(),
+            location.bright_black(),
+            mark.bright_green()
+        );
+    }
+    println!();
+
+    let mut output = Vec::new();
+    if manifest.get_bins().is_empty() && !manifest.is_library() && !configured {
+        output.push(main_class_not_listed(
+            &main_class,
+            source::suggested_main(&mains, manifest.get_name()),
+        ));
+    }
+    Ok(output)
+}
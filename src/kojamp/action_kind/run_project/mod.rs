@@ -1,4 +1,5 @@
 mod main;
+mod mains;
 mod reports;
 mod utils;
 
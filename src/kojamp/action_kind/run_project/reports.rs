@@ -1,14 +1,28 @@
-use crate::core::reporting::{messages, KojampReport, ReportType};
+use crate::core::{
+    models::{GraphError, ManifestError, ProjectBin, TargetError},
+    reporting::{messages, KojampReport, ReportType},
+};
 use std::path::Path;
 
 const COULD_NOT_READ_PROJECT_FOLDER: &str = "Couldn't read project folder";
-const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str = "Couldn't get the current directory";
+const COULD_NOT_GET_THE_CURRENT_DIRECTORY: &str =
+    "Couldn't get the current directory";
 const PROBABLY_A_NON_KOJAMP_PROJECT: &str = "Probably a non kojamp project";
 const COULD_NOT_READ_TOML_FILE: &str = "Couldn't read toml file";
-const COULD_NOT_GET_PROJECT_NAME_FROM_TOML: &str = "Couldn't get project name from toml";
-const COULD_NOT_GET_PROJECT_KIND_FROM_TOML: &str = "Couldn't get project kind from toml";
+const INVALID_TOML_FILE: &str = "Invalid toml file";
 const OUTPUT_FILE_DOESNT_EXISTS: &str = "Output file doesn't exists";
-const COULD_NOT_GENERATE_OUTPUT_FILE_NAME: &str = "Couldn't generate output file name";
+const NOT_A_WORKSPACE: &str = "Not a workspace";
+const MEMBER_REQUIRED: &str = "No workspace member specified";
+const UNKNOWN_MEMBER: &str = "Unknown workspace member";
+const INVALID_PROJECT_DEPENDENCY: &str = "Invalid project at `$$$`";
+const DEPENDENCY_CYCLE: &str = "Dependency cycle";
+const LIBRARY_CANT_BE_RUN: &str = "Libraries can't be run";
+const NO_BIN_ENTRIES: &str = "No `[[bin]]` entries";
+const UNKNOWN_BIN: &str = "Unknown `[[bin]]` entry";
+const BIN_REQUIRED: &str = "No `[[bin]]` entry specified";
+const UNREADABLE_SRC_CONTENT: &str = "Unreadable src content";
+const NO_MAINS_FOUND: &str = "No main entries found";
+const MAIN_CLASS_NOT_LISTED: &str = "The main class has no main method";
 
 pub fn could_not_get_curdir() -> KojampReport {
     KojampReport::new(
@@ -34,42 +48,141 @@ pub fn could_not_read_dir() -> KojampReport {
     )
 }
 
-pub fn could_not_read_toml_file() -> KojampReport {
+pub fn manifest_error(error: ManifestError) -> KojampReport {
+    match error {
+        ManifestError::Missing => kojamp_toml_is_missing(),
+        ManifestError::Unreadable => KojampReport::new(
+            ReportType::Error,
+            COULD_NOT_READ_TOML_FILE,
+            messages::toml_file_could_not_be_read(),
+        ),
+        ManifestError::Invalid => KojampReport::new(
+            ReportType::Error,
+            INVALID_TOML_FILE,
+            messages::invalid_toml_file(),
+        ),
+    }
+}
+
+pub fn output_file_doesnt_exists(file_path: &Path) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_READ_TOML_FILE,
-        messages::toml_file_could_not_be_read(),
+        OUTPUT_FILE_DOESNT_EXISTS,
+        messages::output_file_doesnt_exists(file_path),
     )
 }
 
-pub fn could_not_get_project_name_from_toml() -> KojampReport {
+pub fn not_a_workspace() -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_GET_PROJECT_NAME_FROM_TOML,
-        messages::empty_message(),
+        NOT_A_WORKSPACE,
+        messages::not_a_workspace(),
     )
 }
 
-pub fn could_not_get_project_kind_from_toml() -> KojampReport {
+pub fn member_required(members: &[String]) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_GET_PROJECT_KIND_FROM_TOML,
-        messages::empty_message(),
+        MEMBER_REQUIRED,
+        messages::workspace_member_required("run", members),
     )
 }
 
-pub fn output_file_doesnt_exists(file_path: &Path) -> KojampReport {
+pub fn unknown_member(name: &str, members: &[String]) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        OUTPUT_FILE_DOESNT_EXISTS,
-        messages::output_file_doesnt_exists(file_path),
+        UNKNOWN_MEMBER,
+        messages::unknown_workspace_member(name, members),
     )
 }
 
-pub fn could_not_generate_output_file_path(name: &str, kind: &str) -> KojampReport {
+pub fn graph_error(error: GraphError) -> KojampReport {
+    match error {
+        GraphError::Manifest(path, e) => KojampReport::new(
+            ReportType::Error,
+            INVALID_PROJECT_DEPENDENCY.replace("$$$", &path.to_string_lossy()),
+            match e {
+                ManifestError::Missing => messages::kojamp_toml_not_found(),
+                ManifestError::Unreadable => {
+                    messages::toml_file_could_not_be_read()
+                }
+                ManifestError::Invalid => messages::invalid_toml_file(),
+            },
+        ),
+        GraphError::Cycle(cycle) => KojampReport::new(
+            ReportType::Error,
+            DEPENDENCY_CYCLE,
+            messages::dependency_cycle(&cycle),
+        ),
+    }
+}
+
+pub fn target_error(error: TargetError) -> KojampReport {
+    match error {
+        TargetError::Manifest(e) => manifest_error(e),
+        TargetError::NotAWorkspace => not_a_workspace(),
+        TargetError::Graph(e) => graph_error(e),
+        TargetError::UnknownMember(name, members) => {
+            unknown_member(&name, &members)
+        }
+    }
+}
+
+pub fn library_cant_be_run(name: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        LIBRARY_CANT_BE_RUN,
+        messages::library_cant_be_run(name),
+    )
+}
+
+pub fn no_bin_entries(name: &str) -> KojampReport {
     KojampReport::new(
         ReportType::Error,
-        COULD_NOT_GENERATE_OUTPUT_FILE_NAME,
-        messages::could_not_generate_output_file_path(name, kind),
+        NO_BIN_ENTRIES,
+        messages::no_bin_entries(name),
+    )
+}
+
+pub fn unknown_bin(name: &str, bins: &[ProjectBin]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNKNOWN_BIN,
+        messages::unknown_bin(name, bins),
+    )
+}
+
+pub fn bin_required(bins: &[ProjectBin]) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        BIN_REQUIRED,
+        messages::bin_required(bins),
+    )
+}
+
+pub fn unreadable_source(source_dir: &str, path: &Path) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        UNREADABLE_SRC_CONTENT,
+        messages::unreadable_src_content(source_dir, path),
+    )
+}
+
+pub fn no_mains_found(source_dir: &str) -> KojampReport {
+    KojampReport::new(
+        ReportType::Error,
+        NO_MAINS_FOUND,
+        messages::no_mains_found(source_dir),
+    )
+}
+
+pub fn main_class_not_listed(
+    main_class: &str,
+    suggestion: Option<&str>,
+) -> KojampReport {
+    KojampReport::new(
+        ReportType::Warning,
+        MAIN_CLASS_NOT_LISTED,
+        messages::main_class_not_listed(main_class, suggestion),
     )
 }
@@ -1,63 +1,71 @@
+use crate::{
+    kojamp::action::build_project::utils::is_module_path_entry, utils::source,
+};
 use std::{
-    fs,
+    env,
     path::{Path, PathBuf},
     process::Command,
 };
 
-pub fn item_is_here<T: AsRef<str>>(path: &Path, file: T) -> Result<bool, ()> {
-    Ok(fs::read_dir(path)
-        .map_err(|_| ())?
-        .filter_map(|entry| entry.ok())
-        .any(|entry| {
-            entry
-                .file_name()
-                .to_str()
-                .map(|name| name == file.as_ref())
-                .unwrap_or(false)
-        }))
-}
-
-pub fn get_project_name_from_toml(file_input: &str) -> Result<String, ()> {
-    let binding = file_input.parse::<toml::Value>().map_err(|_| ())?;
-    let project_table = binding.get("project").ok_or(())?;
-    let name_field = project_table.get("name").ok_or(())?.as_str().ok_or(())?;
-    Ok(String::from(name_field))
-}
-
-pub fn get_project_kind_from_toml(file_input: &str) -> Result<String, ()> {
-    let binding = file_input.parse::<toml::Value>().map_err(|_| ())?;
-    let project_table = binding.get("project").ok_or(())?;
-    let kind_field = project_table.get("kind").ok_or(())?.as_str().ok_or(())?;
-    Ok(String::from(kind_field))
-}
-
-pub fn try_generate_output_path(name: &str, kind: &str) -> Option<PathBuf> {
-    let mut output_name = PathBuf::from("out").join(name);
-    let try_setting = match kind {
-        "java" => output_name.set_extension("class"),
-        "kotlin" => output_name.set_extension("jar"),
-        _ => false,
-    };
-    if try_setting {
-        Some(output_name)
-    } else {
-        None
+pub fn run_with_classpath(
+    classpath: &[PathBuf],
+    main_class: &str,
+    jvm_options: &[String],
+) {
+    let mut command = Command::new("java");
+    command.args(jvm_options);
+    if let Ok(joined) = env::join_paths(classpath) {
+        command.arg("--class-path").arg(joined);
     }
+    let _ = command.arg(main_class).status();
 }
 
-pub fn output_exists(curdir: &Path, output_path: &Path) -> (bool, PathBuf) {
-    let abs_path = curdir.join(output_path);
-    (abs_path.exists() && abs_path.is_file(), abs_path)
+// the module holding the main class, plus its classes dir (a single module
+// is built right at `out`, while many ones at `out/<module>`)
+pub fn main_module(
+    out_dir: &Path,
+    source_dir: &Path,
+    main_class: &str,
+) -> Option<(String, PathBuf)> {
+    let modules = source::find_modules(source_dir);
+    let class_file = format!("{}.class", main_class.replace('.', "/"));
+    match modules.as_slice() {
+        [] => None,
+        [(_, module)] => Some((module.name.clone(), out_dir.to_path_buf())),
+        _ => {
+            let mut built = modules
+                .iter()
+                .map(|(_, m)| (m.name.clone(), out_dir.join(&m.name)));
+            built
+                .clone()
+                .find(|(_, dir)| dir.join(&class_file).is_file())
+                .or(built.next())
+        }
+    }
 }
 
-pub fn run_output(kind: &str, path: &Path) {
+pub fn run_module(
+    module_path: &[PathBuf],
+    module: &str,
+    main_class: &str,
+    jvm_options: &[String],
+) {
     let mut command = Command::new("java");
-    if kind == "kotlin" {
-        command.arg("-jar").arg(path);
-    } else {
-        command
-            .args(["--class-path", "out"])
-            .arg(path.file_stem().unwrap());
+    command.args(jvm_options);
+    let (modules, classes): (Vec<&PathBuf>, Vec<&PathBuf>) =
+        module_path.iter().partition(|e| is_module_path_entry(e));
+    for (flag, entries) in
+        [("--module-path", modules), ("--class-path", classes)]
+    {
+        match env::join_paths(entries) {
+            Ok(joined) if !joined.is_empty() => {
+                command.arg(flag).arg(joined);
+            }
+            _ => {}
+        }
     }
-    let _ = command.status();
+    let _ = command
+        .arg("--module")
+        .arg(format!("{}/{}", module, main_class))
+        .status();
 }
@@ -11,7 +11,19 @@ type MatchingAlias = Option<(Rc<str>, ArgMatches)>;
 
 const CREATE_PROJECT_COMMANDS: [&str; 3] = ["new", "init", "ini"];
 const BUILD_PROJECT_COMMANDS: [&str; 2] = ["build", "b"];
+const CHECK_PROJECT_COMMANDS: [&str; 2] = ["check", "c"];
 const RUN_PROJECT_COMMANDS: [&str; 2] = ["run", "r"];
+const ADD_COMMANDS: [&str; 1] = ["add"];
+const REMOVE_COMMANDS: [&str; 2] = ["remove", "rm"];
+const EXPORT_COMMANDS: [&str; 1] = ["export"];
+const IMPORT_COMMANDS: [&str; 1] = ["import"];
+const INSTALL_COMMANDS: [&str; 1] = ["install"];
+const DEPS_COMMANDS: [&str; 1] = ["deps"];
+const LINT_COMMANDS: [&str; 1] = ["lint"];
+const FMT_COMMANDS: [&str; 1] = ["fmt"];
+const DOC_COMMANDS: [&str; 1] = ["doc"];
+const DIST_COMMANDS: [&str; 1] = ["dist"];
+const CONFIG_COMMANDS: [&str; 1] = ["config"];
 
 #[derive(Default)]
 pub struct KojampBuilder {
@@ -97,9 +109,52 @@ impl KojampApp for Command {
         let matching = matching.unwrap();
         let output: Result<Vec<KojampReport>, Vec<KojampReport>> =
             match (matching.0.as_ref(), matching.1) {
-                (x, m) if CREATE_PROJECT_COMMANDS.contains(&x) => action::new_project((x, m)),
-                (x, _) if BUILD_PROJECT_COMMANDS.contains(&x) => action::build_project(),
-                (x, _) if RUN_PROJECT_COMMANDS.contains(&x) => action::run_project(),
+                (x, m) if CREATE_PROJECT_COMMANDS.contains(&x) => {
+                    action::new_project((x, m))
+                }
+                (x, m) if BUILD_PROJECT_COMMANDS.contains(&x) => {
+                    action::build_project(m)
+                }
+                (x, m) if CHECK_PROJECT_COMMANDS.contains(&x) => {
+                    action::check_project(m)
+                }
+                (x, m) if RUN_PROJECT_COMMANDS.contains(&x) => {
+                    action::run_project(m)
+                }
+                (x, m) if ADD_COMMANDS.contains(&x) => {
+                    match m.subcommand_name() {
+                        Some("dep") => action::add_dependency(m),
+                        _ => action::add_source(m),
+                    }
+                }
+                (x, m) if REMOVE_COMMANDS.contains(&x) => {
+                    action::remove_dependency(m)
+                }
+                (x, m) if EXPORT_COMMANDS.contains(&x) => {
+                    action::export_project(m)
+                }
+                (x, m) if IMPORT_COMMANDS.contains(&x) => {
+                    action::import_project(m)
+                }
+                (x, m) if INSTALL_COMMANDS.contains(&x) => {
+                    action::install_project(m)
+                }
+                (x, m) if DEPS_COMMANDS.contains(&x) => {
+                    action::inspect_dependencies(m)
+                }
+                (x, m) if LINT_COMMANDS.contains(&x) => action::lint_project(m),
+                (x, m) if FMT_COMMANDS.contains(&x) => {
+                    action::format_sources(m)
+                }
+                (x, m) if DOC_COMMANDS.contains(&x) => {
+                    action::document_project(m)
+                }
+                (x, m) if DIST_COMMANDS.contains(&x) => {
+                    action::package_distribution(m)
+                }
+                (x, m) if CONFIG_COMMANDS.contains(&x) => {
+                    action::manage_config(m)
+                }
                 // if matching isn't None and it's different from the matches above, alert:
                 _ => Err(Vec::from([KojampReport::new(
                     ReportType::Error,
@@ -110,7 +165,10 @@ impl KojampApp for Command {
         output
     }
 
-    fn exit_output(&self, output: Result<Vec<KojampReport>, Vec<KojampReport>>) {
+    fn exit_output(
+        &self,
+        output: Result<Vec<KojampReport>, Vec<KojampReport>>,
+    ) {
         let (reports, exit): (Vec<KojampReport>, i32) = match output {
             Ok(r) => (r, SUCCESS_EXIT_STATUS),
             Err(r) => (r, FAILURE_EXIT_STATUS),
@@ -10,7 +10,19 @@ pub fn kojamp_app() -> Command {
         subcmds::new::cmd(),
         subcmds::init::cmd(),
         subcmds::build::cmd(),
+        subcmds::check::cmd(),
         subcmds::run::cmd(),
+        subcmds::add::cmd(),
+        subcmds::remove::cmd(),
+        subcmds::export::cmd(),
+        subcmds::import::cmd(),
+        subcmds::install::cmd(),
+        subcmds::deps::cmd(),
+        subcmds::lint::cmd(),
+        subcmds::fmt::cmd(),
+        subcmds::doc::cmd(),
+        subcmds::dist::cmd(),
+        subcmds::config::cmd(),
     ];
 
     let mut app: Command = Command::new_app()
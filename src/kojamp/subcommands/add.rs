@@ -0,0 +1,81 @@
+use clap::{Arg, ArgAction, Command};
+use colored::Colorize;
+
+pub const SOURCE_KINDS: [&str; 6] = [
+    "class",
+    "interface",
+    "enum",
+    "record",
+    "object",
+    "data-class",
+];
+
+fn source_cmd(kind: &'static str) -> Command {
+    let target = match kind {
+        "record" => "(Java only)",
+        "object" | "data-class" => "(Kotlin only)",
+        _ => "(Java/Kotlin)",
+    };
+
+    Command::new(kind)
+        .about(format!("Create a new {} source file {}", kind, target))
+        .args([
+            Arg::new("name")
+                .value_name("CAMEL_CASED_NAME")
+                .action(ArgAction::Set)
+                .required(true)
+                .help(format!("Specifies the {} name", kind))
+                .index(1),
+            Arg::new("package")
+                .long("package")
+                .short('p')
+                .required(false)
+                .value_name("PACKAGE")
+                .action(ArgAction::Set)
+                .help(format!(
+                    "Specifies the source package {}",
+                    "(user config `package-prefix` as default)"
+                        .bright_black()
+                        .italic()
+                )),
+        ])
+}
+
+fn dependency_cmd() -> Command {
+    Command::new("dep")
+        .about("Add a dependency to the `Kojamp.toml` file")
+        .args([
+            Arg::new("dependency")
+                .value_name("COORDINATE|PATH")
+                .action(ArgAction::Set)
+                .required(true)
+                .help(format!(
+                    "Specifies the dependency {}",
+                    "(group:artifact:version, a .jar or a kojamp project dir)"
+                        .bright_black()
+                        .italic()
+                ))
+                .index(1),
+            Arg::new("name")
+                .long("name")
+                .short('n')
+                .required(false)
+                .value_name("NAME")
+                .action(ArgAction::Set)
+                .help(format!(
+                    "Specifies the dependency name {}",
+                    "(artifact, file or dir name as default)"
+                        .bright_black()
+                        .italic()
+                )),
+        ])
+}
+
+pub fn cmd() -> Command {
+    Command::new("add")
+        .about("Add an item to the current project")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommands(SOURCE_KINDS.map(source_cmd))
+        .subcommand(dependency_cmd())
+}
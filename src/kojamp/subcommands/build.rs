@@ -1,7 +1,16 @@
-use clap::Command;
+use clap::{Arg, ArgAction, Command};
 
 pub fn cmd() -> Command {
     Command::new("build")
         .visible_alias("b")
         .about("Build the project bytecode (.class/.jar)")
+        .arg(
+            Arg::new("package")
+                .long("package")
+                .short('p')
+                .required(false)
+                .value_name("MEMBER")
+                .action(ArgAction::Set)
+                .help("Build a single workspace member (and its dependencies)"),
+        )
 }
@@ -0,0 +1,16 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn cmd() -> Command {
+    Command::new("check")
+        .visible_alias("c")
+        .about("Compile the sources without writing to the `out` dir")
+        .arg(
+            Arg::new("package")
+                .long("package")
+                .short('p')
+                .required(false)
+                .value_name("MEMBER")
+                .action(ArgAction::Set)
+                .help("Check a single workspace member (and its dependencies)"),
+        )
+}
@@ -0,0 +1,37 @@
+use crate::core::models::USER_CONFIG_KEYS;
+use clap::{Arg, ArgAction, Command};
+
+fn key_arg() -> Arg {
+    Arg::new("key")
+        .value_name("KEY")
+        .action(ArgAction::Set)
+        .required(true)
+        .value_parser(USER_CONFIG_KEYS)
+        .help("Specifies the config key")
+        .index(1)
+}
+
+pub fn cmd() -> Command {
+    Command::new("config")
+        .about("Manage the user config (default values for new projects)")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommands([
+            Command::new("get")
+                .about("Print the value of a config key")
+                .arg(key_arg()),
+            Command::new("set")
+                .about("Set the value of a config key")
+                .args([
+                    key_arg(),
+                    Arg::new("value")
+                        .value_name("VALUE")
+                        .action(ArgAction::Set)
+                        .required(true)
+                        .help("Specifies the config value")
+                        .index(2),
+                ]),
+            Command::new("list")
+                .about("List every config key and where its value comes from"),
+        ])
+}
@@ -0,0 +1,26 @@
+use clap::{Arg, ArgAction, Command};
+
+fn package_arg() -> Arg {
+    Arg::new("package")
+        .long("package")
+        .short('p')
+        .required(false)
+        .value_name("MEMBER")
+        .action(ArgAction::Set)
+        .help("Inspect a single workspace member")
+}
+
+pub fn cmd() -> Command {
+    Command::new("deps")
+        .about("Inspect the project dependencies")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommands([
+            Command::new("tree")
+                .about("Print the resolved dependency tree (evicted versions included)")
+                .arg(package_arg()),
+            Command::new("check")
+                .about("Look for classes defined more than once at the classpath")
+                .arg(package_arg()),
+        ])
+}
@@ -0,0 +1,22 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn cmd() -> Command {
+    Command::new("dist")
+        .about("Pack the program, its dependencies and launchers at `out/dist`")
+        .arg(
+            Arg::new("package")
+                .long("package")
+                .short('p')
+                .required(false)
+                .value_name("MEMBER")
+                .action(ArgAction::Set)
+                .help("Distribute a single workspace member"),
+        )
+        .arg(
+            Arg::new("jlink")
+                .long("jlink")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Also bundle a trimmed java runtime at `out/image`"),
+        )
+}
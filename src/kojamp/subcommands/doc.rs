@@ -0,0 +1,17 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn cmd() -> Command {
+    Command::new("doc")
+        .about(
+            "Generate the html documentation (javadoc or dokka) at `out/doc`",
+        )
+        .arg(
+            Arg::new("package")
+                .long("package")
+                .short('p')
+                .required(false)
+                .value_name("MEMBER")
+                .action(ArgAction::Set)
+                .help("Document a single workspace member"),
+        )
+}
@@ -0,0 +1,21 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn cmd() -> Command {
+    Command::new("export")
+        .about("Generate a Gradle/Maven build from the `Kojamp.toml` file")
+        .args([
+            Arg::new("tool")
+                .value_name("GRADLE|MAVEN")
+                .action(ArgAction::Set)
+                .required(true)
+                .value_parser(["gradle", "maven"])
+                .help("Specifies the build tool")
+                .index(1),
+            Arg::new("force")
+                .long("force")
+                .short('f')
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Overwrite the build files if they already exist"),
+        ])
+}
@@ -0,0 +1,20 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn cmd() -> Command {
+    Command::new("fmt")
+        .about("Format the sources (google-java-format, ktlint or ktfmt)")
+        .args([
+            Arg::new("check")
+                .long("check")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("List the unformatted files without modifying them"),
+            Arg::new("package")
+                .long("package")
+                .short('p')
+                .required(false)
+                .value_name("MEMBER")
+                .action(ArgAction::Set)
+                .help("Format a single workspace member"),
+        ])
+}
@@ -0,0 +1,14 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn cmd() -> Command {
+    Command::new("import")
+        .about("Generate a `Kojamp.toml` file from a Maven/Gradle build")
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .short('f')
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Overwrite the `Kojamp.toml` file if it already exists"),
+        )
+}
@@ -8,7 +8,7 @@ pub fn cmd() -> Command {
             Arg::new("name")
                 .value_name("CAMEL_CASED_NAME")
                 .action(ArgAction::Set)
-                .required(true)
+                .required_unless_present("adopt")
                 .help("Specifies the project name")
                 .index(1),
             Arg::new("kind")
@@ -25,6 +25,24 @@ pub fn cmd() -> Command {
                 .value_name("QUOTED_LIST")
                 .action(ArgAction::Set)
                 .help("Specifies the project authors"),
+            Arg::new("template")
+                .long("template")
+                .short('t')
+                .required(false)
+                .value_name("GREETING|MINIMAL")
+                .action(ArgAction::Set)
+                .help("Specifies the main file template"),
+            Arg::new("lib")
+                .long("lib")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Create a library (no main class, built into a versioned jar)"),
+            Arg::new("git")
+                .long("git")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .conflicts_with("no-git")
+                .help("Initialize a git repo even if the user config disables it"),
             Arg::new("no-git")
                 .long("no-git")
                 .required(false)
@@ -36,5 +54,16 @@ pub fn cmd() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
                 .help("Force project initialization in a non empty dir"),
+            Arg::new("adopt")
+                .long("adopt")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["template", "force"])
+                .help("Adopt the existing sources instead of generating a main file"),
+            Arg::new("dry-run")
+                .long("dry-run")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Print the planned file tree without touching the disk"),
         ])
 }
@@ -0,0 +1,7 @@
+use clap::Command;
+
+pub fn cmd() -> Command {
+    Command::new("install").about(
+        "Build the project jar and publish it to the local maven repository",
+    )
+}
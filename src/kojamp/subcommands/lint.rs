@@ -0,0 +1,23 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn cmd() -> Command {
+    Command::new("lint")
+        .about("Check the sources layout and naming (without compiling)")
+        .args([
+            Arg::new("package")
+                .long("package")
+                .short('p')
+                .required(false)
+                .value_name("MEMBER")
+                .action(ArgAction::Set)
+                .help("Lint a single workspace member"),
+            Arg::new("deny")
+                .long("deny")
+                .short('D')
+                .required(false)
+                .value_name("LEVEL")
+                .value_parser(["warnings"])
+                .action(ArgAction::Set)
+                .help("Fail the command when any warning is found"),
+        ])
+}
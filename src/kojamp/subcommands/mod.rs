@@ -1,4 +1,16 @@
+pub mod add;
 pub mod build;
+pub mod check;
+pub mod config;
+pub mod deps;
+pub mod dist;
+pub mod doc;
+pub mod export;
+pub mod fmt;
+pub mod import;
 pub mod init;
+pub mod install;
+pub mod lint;
 pub mod new;
+pub mod remove;
 pub mod run;
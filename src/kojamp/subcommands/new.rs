@@ -8,8 +8,11 @@ pub fn cmd() -> Command {
             Arg::new("name")
                 .value_name("CAMEL_CASED_NAME")
                 .action(ArgAction::Set)
-                .required(true)
-                .help("Specifies the project name")
+                .required(false)
+                .help(format!(
+                    "Specifies the project name {}",
+                    "(prompted when omitted)".bright_black().italic()
+                ))
                 .index(1),
             Arg::new("kind")
                 .long("kind")
@@ -37,10 +40,39 @@ pub fn cmd() -> Command {
                 .value_name("QUOTED_LIST")
                 .action(ArgAction::Set)
                 .help("Specifies the project authors"),
+            Arg::new("template")
+                .long("template")
+                .short('t')
+                .required(false)
+                .value_name("GREETING|MINIMAL")
+                .action(ArgAction::Set)
+                .help("Specifies the main file template"),
+            Arg::new("lib")
+                .long("lib")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Create a library (no main class, built into a versioned jar)"),
+            Arg::new("git")
+                .long("git")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .conflicts_with("no-git")
+                .help("Initialize a git repo even if the user config disables it"),
             Arg::new("no-git")
                 .long("no-git")
                 .required(false)
                 .action(ArgAction::SetFalse)
                 .help("No git repo initialization when starting new project"),
+            Arg::new("interactive")
+                .long("interactive")
+                .short('i')
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Prompt for every project field before creating it"),
+            Arg::new("dry-run")
+                .long("dry-run")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Print the planned file tree without touching the disk"),
         ])
 }
@@ -0,0 +1,21 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn cmd() -> Command {
+    Command::new("remove")
+        .visible_alias("rm")
+        .about("Remove an item from the current project")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("dep")
+                .about("Remove a dependency from the `Kojamp.toml` file")
+                .arg(
+                    Arg::new("name")
+                        .value_name("NAME")
+                        .action(ArgAction::Set)
+                        .required(true)
+                        .help("Specifies the dependency name")
+                        .index(1),
+                ),
+        )
+}
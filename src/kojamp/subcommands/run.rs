@@ -1,7 +1,29 @@
-use clap::Command;
+use clap::{Arg, ArgAction, Command};
 
 pub fn cmd() -> Command {
     Command::new("run")
         .visible_alias("r")
         .about("Run the compiled bytecode")
+        .args([
+            Arg::new("package")
+                .long("package")
+                .short('p')
+                .required(false)
+                .value_name("MEMBER")
+                .action(ArgAction::Set)
+                .help("Run the given workspace member"),
+            Arg::new("bin")
+                .long("bin")
+                .short('b')
+                .required(false)
+                .value_name("NAME")
+                .action(ArgAction::Set)
+                .help("Run the given `[[bin]]` entry"),
+            Arg::new("list-mains")
+                .long("list-mains")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .conflicts_with("bin")
+                .help("List the main entries found at the sources"),
+        ])
 }
@@ -2,15 +2,25 @@ mod core;
 mod kojamp;
 mod utils;
 
+use crate::core::models::UserConfig;
+use colored::Colorize;
 use kojamp::{kojamp_app, KojampApp};
 
 fn big_warning() {
-    println!("\x1b[93mwarning\x1b[0m: this project is no longer maintained.");
+    println!(
+        "{}: this project is no longer maintained.",
+        "warning".bright_yellow()
+    );
     println!();
-    println!("JSmoke (https://github.com/nasccped/jsmoke) is/will be the successor!");
+    println!(
+        "JSmoke (https://github.com/nasccped/jsmoke) is/will be the successor!"
+    );
 }
 
 fn main() {
+    if UserConfig::load().is_ok_and(|c| c.plain_output()) {
+        colored::control::set_override(false);
+    }
     big_warning();
     let mut app = kojamp_app();
     let matching = app.get_matching();
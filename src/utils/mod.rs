@@ -1 +1,4 @@
+pub mod path;
+pub mod source;
 pub mod string;
+pub mod time;
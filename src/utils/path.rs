@@ -0,0 +1,9 @@
+use std::path::Path;
+
+// paths are shown relative to `base` (when possible), with `/` separators
+pub fn display(path: &Path, base: &Path) -> String {
+    path.strip_prefix(base)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
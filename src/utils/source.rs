@@ -0,0 +1,734 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// a light java/kotlin lexer: comments are skipped and string contents are
+// kept apart, so `main` inside them is never taken as a declaration
+#[derive(PartialEq)]
+pub enum Token {
+    Ident(String),
+    Str(String),
+    Number,
+    Punct(char),
+}
+
+const JAVA_TYPE_KEYWORDS: [&str; 4] = ["class", "interface", "enum", "record"];
+const KOTLIN_TYPE_KEYWORDS: [&str; 3] = ["class", "interface", "object"];
+
+struct Lexer<'a> {
+    chars: &'a [char],
+    index: usize,
+    line: usize,
+    kotlin: bool,
+}
+
+impl Lexer<'_> {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.index + offset).copied()
+    }
+
+    fn starts_with(&self, pattern: &str) -> bool {
+        pattern
+            .chars()
+            .enumerate()
+            .all(|(i, c)| self.peek(i) == Some(c))
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.index += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    // kotlin block comments can be nested, java ones can't
+    fn skip_block_comment(&mut self) {
+        let mut depth = 0;
+        while self.peek(0).is_some() {
+            if self.starts_with("/*") && (depth == 0 || self.kotlin) {
+                self.index += 2;
+                depth += 1;
+            } else if self.starts_with("*/") {
+                self.index += 2;
+                depth -= 1;
+                if depth == 0 {
+                    return;
+                }
+            } else {
+                self.bump();
+            }
+        }
+    }
+
+    // `${...}` kotlin templates may hold nested strings
+    fn skip_template(&mut self) {
+        let mut depth = 0;
+        while let Some(c) = self.peek(0) {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.bump();
+                        return;
+                    }
+                }
+                '"' => {
+                    self.string();
+                    continue;
+                }
+                _ => {}
+            }
+            self.bump();
+        }
+    }
+
+    // text blocks / raw strings (`"""`) and regular ones
+    fn string(&mut self) -> String {
+        let mut output = String::new();
+
+        if self.starts_with("\"\"\"") {
+            self.index += 3;
+            while self.peek(0).is_some() && !self.starts_with("\"\"\"") {
+                if self.kotlin && self.starts_with("${") {
+                    self.index += 1;
+                    self.skip_template();
+                    continue;
+                }
+                output.extend(self.bump());
+            }
+            self.index += 3;
+            // kotlin raw strings may end with extra quotes (`""""`)
+            while self.peek(0) == Some('"') {
+                output.extend(self.bump());
+            }
+            return output;
+        }
+
+        self.bump();
+        while let Some(c) = self.peek(0) {
+            match c {
+                '"' | '\n' => break,
+                '\\' => {
+                    self.bump();
+                    output.extend(self.bump());
+                }
+                '$' if self.kotlin && self.peek(1) == Some('{') => {
+                    self.bump();
+                    self.skip_template();
+                }
+                _ => output.extend(self.bump()),
+            }
+        }
+        self.bump();
+        output
+    }
+
+    fn char_literal(&mut self) {
+        self.bump();
+        while let Some(c) = self.bump() {
+            match c {
+                '\\' => {
+                    self.bump();
+                }
+                '\'' | '\n' => return,
+                _ => {}
+            }
+        }
+    }
+
+    fn word(&mut self) -> String {
+        let mut output = String::new();
+        while let Some(c) = self.peek(0) {
+            if !(c.is_alphanumeric() || c == '_' || c == '$') {
+                break;
+            }
+            output.push(c);
+            self.index += 1;
+        }
+        output
+    }
+
+    fn next_token(&mut self) -> Option<(Token, usize)> {
+        loop {
+            let c = self.peek(0)?;
+            let line = self.line;
+
+            if c.is_whitespace() {
+                self.bump();
+            } else if self.starts_with("//") {
+                while self.peek(0).is_some_and(|c| c != '\n') {
+                    self.bump();
+                }
+            } else if self.starts_with("/*") {
+                self.skip_block_comment();
+            } else if c == '"' {
+                return Some((Token::Str(self.string()), line));
+            } else if c == '\'' {
+                self.char_literal();
+            } else if c == '`' && self.kotlin {
+                self.bump();
+                let mut name = String::new();
+                while let Some(c) = self.bump() {
+                    if c == '`' || c == '\n' {
+                        break;
+                    }
+                    name.push(c);
+                }
+                return Some((Token::Ident(name), line));
+            } else if c.is_ascii_digit() {
+                // `1.5f`, `0x1F`, `1_000L`
+                while self.peek(0).is_some_and(|c| {
+                    c.is_alphanumeric() || c == '_' || c == '.'
+                }) {
+                    self.bump();
+                }
+                return Some((Token::Number, line));
+            } else if c.is_alphabetic() || c == '_' || c == '$' {
+                return Some((Token::Ident(self.word()), line));
+            } else {
+                self.bump();
+                return Some((Token::Punct(c), line));
+            }
+        }
+    }
+}
+
+// tokens paired with their line
+pub fn tokenize(content: &str, kind: &str) -> Vec<(Token, usize)> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut lexer = Lexer {
+        chars: &chars,
+        index: 0,
+        line: 1,
+        kotlin: kind == "kotlin",
+    };
+
+    let mut output = Vec::new();
+    while let Some(token) = lexer.next_token() {
+        output.push(token);
+    }
+    output
+}
+
+pub struct TypeDeclaration {
+    pub name: String,
+    pub line: usize,
+    pub public: bool,
+    pub top_level: bool,
+}
+
+pub struct MainMethod {
+    // the binary class name (`com.foo.App`, `com.foo.AppKt`)
+    pub class: String,
+    pub line: usize,
+}
+
+#[derive(Default)]
+pub struct SourceSummary {
+    pub package: Option<(String, usize)>,
+    pub types: Vec<TypeDeclaration>,
+    pub mains: Vec<MainMethod>,
+}
+
+fn is_ident(tokens: &[(Token, usize)], index: usize, name: &str) -> bool {
+    matches!(tokens.get(index), Some((Token::Ident(i), _)) if i == name)
+}
+
+fn is_punct(tokens: &[(Token, usize)], index: usize, punct: char) -> bool {
+    matches!(tokens.get(index), Some((Token::Punct(p), _)) if *p == punct)
+}
+
+fn ident_at(tokens: &[(Token, usize)], index: usize) -> Option<&str> {
+    match tokens.get(index) {
+        Some((Token::Ident(i), _)) => Some(i.as_str()),
+        _ => None,
+    }
+}
+
+// the parameter list (without spaces) and the index after its `)`
+fn parameters(tokens: &[(Token, usize)], open: usize) -> (String, usize) {
+    let mut output = String::new();
+    let mut depth = 0;
+    let mut index = open;
+
+    while let Some((token, _)) = tokens.get(index) {
+        match token {
+            Token::Punct('(') => depth += 1,
+            Token::Punct(')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return (output, index + 1);
+                }
+            }
+            _ => {}
+        }
+        if index > open {
+            match token {
+                Token::Ident(i) if i == "final" => {}
+                Token::Ident(i) => output.push_str(i),
+                Token::Punct(p) => output.push(*p),
+                _ => output.push('?'),
+            }
+        }
+        index += 1;
+    }
+
+    (output, index)
+}
+
+// `a.b.c` starting at `start`
+fn qualified_name(tokens: &[(Token, usize)], start: usize) -> String {
+    let mut output = String::new();
+    let mut index = start;
+    while let Some(part) = ident_at(tokens, index) {
+        output.push_str(part);
+        if !is_punct(tokens, index + 1, '.')
+            || ident_at(tokens, index + 2).is_none()
+        {
+            break;
+        }
+        output.push('.');
+        index += 2;
+    }
+    output
+}
+
+fn with_package(package: &Option<(String, usize)>, class: &str) -> String {
+    match package {
+        Some((p, _)) => format!("{}.{}", p, class),
+        None => class.to_string(),
+    }
+}
+
+// the package, the declared types and every `main` entry point
+pub fn scan(content: &str, kind: &str, file_stem: &str) -> SourceSummary {
+    let tokens = tokenize(content, kind);
+    let kotlin = kind == "kotlin";
+    let type_keywords: &[&str] = match kotlin {
+        true => &KOTLIN_TYPE_KEYWORDS,
+        false => &JAVA_TYPE_KEYWORDS,
+    };
+
+    let mut summary = SourceSummary::default();
+    // kotlin top-level functions live at the `<File>Kt` facade class
+    let mut facade = {
+        let mut chars = file_stem.chars();
+        let first: String = chars
+            .next()
+            .map(|c| c.to_uppercase().collect())
+            .unwrap_or_default();
+        format!("{}{}Kt", first, chars.as_str())
+    };
+
+    // `Some((binary name, is kotlin object))` for type bodies
+    let mut scopes: Vec<Option<(String, bool)>> = Vec::new();
+    let mut pending: Option<(String, bool)> = None;
+    let mut modifiers_start = 0;
+    let mut parens: usize = 0;
+    let mut index = 0;
+
+    while index < tokens.len() {
+        let (token, line) = (&tokens[index].0, tokens[index].1);
+        let enclosing = scopes.last().cloned().flatten();
+        let in_types = scopes.iter().all(|s| s.is_some());
+        let modifiers = |name: &str| {
+            (modifiers_start..index).any(|i| is_ident(&tokens, i, name))
+        };
+
+        match token {
+            Token::Ident(i)
+                if i == "package"
+                    && scopes.is_empty()
+                    && summary.package.is_none() =>
+            {
+                let package = qualified_name(&tokens, index + 1);
+                if !package.is_empty() {
+                    summary.package = Some((package, line));
+                }
+            }
+            // `@file:JvmName("Name")`
+            Token::Ident(i)
+                if kotlin
+                    && i == "file"
+                    && is_punct(&tokens, index.wrapping_sub(1), '@')
+                    && is_punct(&tokens, index + 1, ':')
+                    && is_ident(&tokens, index + 2, "JvmName") =>
+            {
+                if let Some((Token::Str(name), _)) = tokens.get(index + 4) {
+                    facade = name.clone();
+                }
+            }
+            Token::Ident(i)
+                if type_keywords.contains(&i.as_str())
+                    && !is_punct(&tokens, index.wrapping_sub(1), '.')
+                    && !is_punct(&tokens, index.wrapping_sub(1), ':') =>
+            {
+                let is_object = i == "object";
+                let companion = is_object
+                    && is_ident(&tokens, index.wrapping_sub(1), "companion");
+                match ident_at(&tokens, index + 1) {
+                    // companion `@JvmStatic` members land at the outer class
+                    _ if companion && enclosing.is_some() => {
+                        pending =
+                            enclosing.clone().map(|(name, _)| (name, true));
+                    }
+                    Some(name) if !type_keywords.contains(&name) => {
+                        let binary = match &enclosing {
+                            Some((outer, _)) => format!("{}${}", outer, name),
+                            None => with_package(&summary.package, name),
+                        };
+                        summary.types.push(TypeDeclaration {
+                            name: name.to_string(),
+                            line: tokens[index + 1].1,
+                            public: modifiers("public"),
+                            top_level: scopes.is_empty(),
+                        });
+                        pending = Some((binary, is_object));
+                    }
+                    _ => {}
+                }
+            }
+            Token::Ident(i)
+                if i == "main" && is_punct(&tokens, index + 1, '(') =>
+            {
+                let (params, _) = parameters(&tokens, index + 1);
+                // `String[] args`, `String... args` or `String args[]`
+                let java_params = params
+                    .strip_prefix("java.lang.String")
+                    .or(params.strip_prefix("String"))
+                    .is_some_and(|p| {
+                        p.starts_with("[]")
+                            || p.starts_with("...")
+                            || (p.ends_with("[]") && !p.contains(','))
+                    });
+                let java_main = !kotlin
+                    && is_ident(&tokens, index.wrapping_sub(1), "void")
+                    && modifiers("public")
+                    && modifiers("static")
+                    && in_types
+                    && java_params;
+                let kotlin_params = params.is_empty()
+                    || (params.contains(":Array<")
+                        && params.ends_with("String>"))
+                    || (params.starts_with("vararg")
+                        && params.ends_with(":String"));
+                let kotlin_main = kotlin
+                    && is_ident(&tokens, index.wrapping_sub(1), "fun")
+                    && kotlin_params;
+
+                match (java_main, kotlin_main, &enclosing) {
+                    (true, _, Some((class, _))) => {
+                        summary.mains.push(MainMethod {
+                            class: class.clone(),
+                            line,
+                        })
+                    }
+                    (_, true, None) if scopes.is_empty() => {
+                        summary.mains.push(MainMethod {
+                            class: with_package(&summary.package, &facade),
+                            line,
+                        })
+                    }
+                    (_, true, Some((class, true)))
+                        if in_types && modifiers("JvmStatic") =>
+                    {
+                        summary.mains.push(MainMethod {
+                            class: class.clone(),
+                            line,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+            // a body-less kotlin class is followed by other declarations
+            // (primary constructor properties are kept apart by `parens`)
+            Token::Ident(i)
+                if kotlin
+                    && parens == 0
+                    && (i == "fun" || i == "val" || i == "var") =>
+            {
+                pending = None;
+            }
+            Token::Punct('(') => parens += 1,
+            Token::Punct(')') => parens = parens.saturating_sub(1),
+            Token::Punct('{') => {
+                scopes.push(pending.take());
+                modifiers_start = index + 1;
+            }
+            Token::Punct('}') => {
+                scopes.pop();
+                pending = None;
+                modifiers_start = index + 1;
+            }
+            Token::Punct(';') => {
+                pending = None;
+                modifiers_start = index + 1;
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    summary
+}
+
+// every main entry point at the given files (unreadable ones are skipped)
+pub fn find_mains(kind: &str, files: &[PathBuf]) -> Vec<(PathBuf, MainMethod)> {
+    let mut output: Vec<(PathBuf, MainMethod)> = files
+        .iter()
+        .flat_map(|file| {
+            let content = fs::read_to_string(file).unwrap_or_default();
+            let stem = file
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            scan(&content, kind, &stem)
+                .mains
+                .into_iter()
+                .map(|m| (file.clone(), m))
+        })
+        .collect();
+    output.sort_by(|(_, a), (_, b)| a.class.cmp(&b.class));
+    output
+}
+
+// a main class named after the project is preferred, then top-level ones
+pub fn suggested_main<'a>(
+    mains: &'a [(PathBuf, MainMethod)],
+    name: &str,
+) -> Option<&'a str> {
+    let simple_name = |class: &'a str| {
+        let simple = class.rsplit('.').next().unwrap_or(class);
+        simple.strip_suffix("Kt").unwrap_or(simple)
+    };
+    let mut classes = mains.iter().map(|(_, m)| m.class.as_str());
+    classes
+        .clone()
+        .find(|c| simple_name(c) == name)
+        .or(classes.clone().find(|c| !c.contains('$')))
+        .or(classes.next())
+}
+
+// build outputs and tool dirs are never scanned
+pub const SKIPPED_DIRS: [&str; 4] = ["out", "build", "target", "node_modules"];
+
+// every `.java` (or `.kt`) file under `dir`, sorted
+pub fn collect_sources(
+    kind: &str,
+    dir: &Path,
+) -> Result<Vec<PathBuf>, PathBuf> {
+    collect_sources_where(kind, dir, &|_| true)
+}
+
+// the same, but only entering the nested dirs `enter` accepts
+pub fn collect_sources_where(
+    kind: &str,
+    dir: &Path,
+    enter: &dyn Fn(&Path) -> bool,
+) -> Result<Vec<PathBuf>, PathBuf> {
+    let extension = match kind {
+        "java" => "java",
+        _ => "kt",
+    };
+    let mut output = Vec::new();
+
+    for entry in dir.read_dir().map_err(|_| dir.to_path_buf())? {
+        let path = entry.map_err(|_| dir.to_path_buf())?.path();
+        if path.is_dir() {
+            if enter(&path) {
+                output.extend(collect_sources_where(kind, &path, enter)?);
+            }
+        } else if path.extension().is_some_and(|e| e == extension) {
+            output.push(path);
+        }
+    }
+
+    output.sort();
+    Ok(output)
+}
+
+// hidden dirs, build outputs and nested kojamp projects hold no sources of
+// the project being scanned
+pub fn is_scanned_dir(dir: &Path) -> bool {
+    let name = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    !name.starts_with('.')
+        && !SKIPPED_DIRS.contains(&name.as_str())
+        && !dir.join("Kojamp.toml").is_file()
+}
+
+pub const MODULE_INFO_FILE: &str = "module-info.java";
+
+pub struct ModuleRequirement {
+    pub name: String,
+    pub line: usize,
+    // `requires static` ones are only needed when compiling
+    pub optional: bool,
+}
+
+pub struct ModuleDeclaration {
+    pub name: String,
+    pub line: usize,
+    pub requires: Vec<ModuleRequirement>,
+}
+
+// `[open] module a.b { requires [static | transitive] c.d; ... }`
+pub fn module_declaration(content: &str) -> Option<ModuleDeclaration> {
+    let tokens = tokenize(content, "java");
+    let start = (0..tokens.len()).find(|i| {
+        is_ident(&tokens, *i, "module")
+            && ident_at(&tokens, i + 1).is_some()
+            && (*i == 0 || !is_punct(&tokens, i - 1, '.'))
+    })?;
+    let name = qualified_name(&tokens, start + 1);
+
+    let mut requires = Vec::new();
+    let mut index = start + 1;
+    while index < tokens.len() {
+        let statement_start = is_punct(&tokens, index - 1, ';')
+            || is_punct(&tokens, index - 1, '{');
+        if statement_start && is_ident(&tokens, index, "requires") {
+            let line = tokens[index].1;
+            let mut optional = false;
+            index += 1;
+            while let Some(modifier) = ident_at(&tokens, index)
+                .filter(|m| *m == "static" || *m == "transitive")
+                .filter(|_| !is_punct(&tokens, index + 1, ';'))
+            {
+                optional |= modifier == "static";
+                index += 1;
+            }
+            let required = qualified_name(&tokens, index);
+            if !required.is_empty() {
+                requires.push(ModuleRequirement {
+                    name: required,
+                    line,
+                    optional,
+                });
+            }
+        }
+        index += 1;
+    }
+
+    Some(ModuleDeclaration {
+        name,
+        line: tokens[start].1,
+        requires,
+    })
+}
+
+// the modules (and their source dirs) of a modular java project: a single
+// `module-info.java` at the source dir, otherwise one at each module dir
+pub fn find_modules(source_dir: &Path) -> Vec<(PathBuf, ModuleDeclaration)> {
+    let declared = |dir: &Path| {
+        let content = fs::read_to_string(dir.join(MODULE_INFO_FILE)).ok()?;
+        module_declaration(&content).map(|m| (dir.to_path_buf(), m))
+    };
+    if let Some(module) = declared(source_dir) {
+        return vec![module];
+    }
+
+    let mut dirs: Vec<PathBuf> = source_dir
+        .read_dir()
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+    dirs.iter().filter_map(|d| declared(d)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mains(content: &str, kind: &str, file_stem: &str) -> Vec<String> {
+        scan(content, kind, file_stem)
+            .mains
+            .into_iter()
+            .map(|m| m.class)
+            .collect()
+    }
+
+    #[test]
+    fn comments_and_strings_hide_main() {
+        let content = r#"
+            package com.foo;
+
+            // public static void main(String[] args) {}
+            /* public static void main(String[] args) {} */
+            public class App {
+                String text = "static void main(String[] args) {}";
+                char quote = '"';
+            }
+        "#;
+        assert!(mains(content, "java", "App").is_empty());
+    }
+
+    #[test]
+    fn java_main_gets_the_binary_name() {
+        let content = r#"
+            package com.foo;
+
+            public class App {
+                public static void main(String[] args) {}
+            }
+        "#;
+        assert_eq!(mains(content, "java", "App"), ["com.foo.App"]);
+    }
+
+    #[test]
+    fn kotlin_top_level_main_lives_at_the_facade() {
+        let content = r#"
+            package com.foo
+
+            fun main() {
+                println("fun main() {}")
+            }
+        "#;
+        assert_eq!(mains(content, "kotlin", "app"), ["com.foo.AppKt"]);
+    }
+
+    #[test]
+    fn kotlin_raw_strings_hide_main() {
+        let content = "val text = \"\"\"\nfun main() {}\n\"\"\"\n";
+        assert!(mains(content, "kotlin", "Text").is_empty());
+    }
+
+    #[test]
+    fn module_requires_static_is_optional() {
+        let content = r#"
+            // module commented.out { }
+            open module com.foo.app {
+                requires transitive com.foo.util;
+                requires static com.foo.annotations;
+                requires static;
+            }
+        "#;
+        let module = module_declaration(content).unwrap();
+        assert_eq!(module.name, "com.foo.app");
+
+        let requires: Vec<(&str, bool)> = module
+            .requires
+            .iter()
+            .map(|r| (r.name.as_str(), r.optional))
+            .collect();
+        assert_eq!(
+            requires,
+            [
+                ("com.foo.util", false),
+                ("com.foo.annotations", true),
+                ("static", false),
+            ]
+        );
+    }
+}